@@ -1,32 +1,107 @@
 #![no_std]
-use gstd::{collections::HashMap, format, msg, exec, Decode, Encode, String, TypeInfo, Vec};
+use gstd::{
+    collections::{HashMap, HashSet},
+    exec, format, msg, Decode, Encode, String, TypeInfo, Vec,
+};
 use sails_rs::prelude::*;
 use vft_client::vft;
 use vnft_client::vnft;
 use vnft_client::TokenMetadata;
 
 pub type TokenId = U256;
+pub type CampaignId = u64;
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
-pub struct L2eTop {
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Stage {
+    pub vara_value: u128,
+    pub token_value: U256,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum ContractKind {
+    Vft,
+    Vnft,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, TypeInfo)]
+pub enum Role {
+    // manages roles and contracts
+    Admin,
+    // creates campaigns, approves rewards and mints certificates
+    Motivator,
+    // mints certificates of the L2E collection
+    Verifier,
+    Pauser,
+}
+
+// Operations that pausers can halt one by one, queries and refunds are never paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, TypeInfo)]
+pub enum Operation {
+    ApproveBalances,
+    MintApproveNft,
+    TransferBalancesFrom,
+    TransferNftFrom,
+}
+
+// Registered token contract, looked up by its address.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ContractInfo {
+    pub kind: ContractKind,
+    pub symbol: String,
+    pub decimals: u8,
+    pub enabled: bool,
+}
+
+// Motivator reward plan, participants claim the stages one by one in order.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Campaign {
+    pub id: CampaignId,
+    pub motivator: ActorId,
+    pub erc20: ActorId,
+    pub erc721: ActorId,
+    pub stages: Vec<Stage>,
+    pub start_block: u32,
+    pub end_block: u32,
+}
+
+#[derive(Default, Debug)]
+pub struct L2eStorage {
     // spenderid -> <(ownerid, vara balance, token balance)> total balance can be mutli stage claim.
     balances: HashMap<ActorId, Vec<(ActorId, u128, U256)>>,
-    // ownerid -> <(spenderid, nft tokenid, claimed true/false)>
-    nfts: HashMap<ActorId, Vec<(ActorId, TokenId, bool)>>,
-    erc20_address: Vec<ActorId>,
-    erc721_address: Vec<ActorId>,
-    // nft token id num
-    token_id_num: U256,
-    admin_address: Vec<ActorId>,
-    auth_token_owner: Vec<ActorId>,
+    // ownerid -> <(spenderid, nft tokenid, collection, claimed true/false)>
+    nfts: HashMap<ActorId, Vec<(ActorId, TokenId, ActorId, bool)>>,
+    // contract address -> contract metadata
+    contracts: HashMap<ActorId, ContractInfo>,
+    // contracts given to constructor, only motivators can approve or mint them
+    l2e_erc20: ActorId,
+    l2e_erc721: ActorId,
+    admins: HashSet<ActorId>,
+    motivators: HashSet<ActorId>,
+    verifiers: HashSet<ActorId>,
+    pausers: HashSet<ActorId>,
+    // admin proposed by propose_admin, waiting for accept_admin
+    pending_admin: Option<ActorId>,
+    // operations halted by pausers
+    paused: HashSet<Operation>,
+    campaigns: HashMap<CampaignId, Campaign>,
+    campaign_id_num: CampaignId,
+    // (campaignid, spenderid) -> number of stages already claimed
+    claimed_stages: HashMap<(CampaignId, ActorId), u32>,
+    // (ownerid, spenderid) -> erc20 of the tokens approved to spender in approve_balances
+    approved_erc20: HashMap<(ActorId, ActorId), ActorId>,
+    // spenderid -> vara of claimed stages that failed to be sent
+    unpaid_vara: HashMap<ActorId, u128>,
+    // (campaignid, spenderid) of stage claims waiting for cross contract replies
+    pending_claims: HashSet<(CampaignId, ActorId)>,
 }
 
-#[derive(Encode, Decode, TypeInfo)]
-pub enum Event {
+static mut L2E_STORAGE: Option<L2eStorage> = None;
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum L2eError {
     BalancesAlreadyApproved,
     NoAuthToMintL2ENFT,
     NoAuthToApproveL2EToken,
-    TransactionAlreadySend,
     TransactionFailed,
     NoExistVaraApprove,
     NoExistTokenApprove,
@@ -37,15 +112,121 @@ pub enum Event {
     NoExistNFT,
     NoClaimedNFT,
     NoAuthorityAddContractAddress,
-    NoAuthorityAddAuthTokenOwner,
+    NotAdmin,
     AlreadyExistTokenAddress,
     AlreadyExistNFTAddress,
-    AlreadyExistAuthAddress,
+    NoAuthToCreateCampaign,
+    NoAuthToUpdateCampaign,
+    UnknownContract,
+    ContractDisabled,
+    UnexpectedContractInterface,
+    InvalidCampaignPeriod,
+    NoExistCampaign,
+    CampaignNotActive,
+    NoExistStage,
+    StageAlreadyClaimed,
+    PreviousStageNotClaimed,
+    AlreadyClaimedNFT,
+    NoExistApprove,
+    VaraValueMismatch,
+    AdminMustBeProposed,
+    NotPendingAdmin,
+    LastAdmin,
+    NotPauser,
+    Paused,
+    EmptyStage,
+    NoUnpaidVara,
+    ClaimPending,
+    OtherCampaignsActive,
 }
 
-#[service(events = Event)]
+#[derive(Encode, Decode, TypeInfo)]
+pub enum Event {
+    TransactionAlreadySend,
+    BalancesApproved {
+        owner: ActorId,
+        spender: ActorId,
+        vara: u128,
+        tokens: U256,
+        erc20: ActorId,
+    },
+    NftMinted {
+        owner: ActorId,
+        spender: ActorId,
+        token_id: TokenId,
+        collection: ActorId,
+    },
+    RewardClaimed {
+        campaign_id: CampaignId,
+        stage: u32,
+        owner: ActorId,
+        spender: ActorId,
+        vara: u128,
+        tokens: U256,
+    },
+    NftClaimed {
+        owner: ActorId,
+        spender: ActorId,
+        token_id: TokenId,
+        collection: ActorId,
+    },
+    ContractRegistered {
+        address: ActorId,
+        kind: ContractKind,
+    },
+    ContractDisabled {
+        address: ActorId,
+    },
+    ContractRemoved {
+        address: ActorId,
+    },
+    ContractRefreshed {
+        address: ActorId,
+    },
+    RoleGranted {
+        role: Role,
+        to: ActorId,
+    },
+    RoleRevoked {
+        role: Role,
+        from: ActorId,
+    },
+    Refunded {
+        owner: ActorId,
+        spender: ActorId,
+        vara: u128,
+        tokens: U256,
+    },
+    AdminProposed {
+        from: ActorId,
+        to: ActorId,
+    },
+    AdminAccepted {
+        admin: ActorId,
+    },
+    Paused {
+        operation: Operation,
+    },
+    Unpaused {
+        operation: Operation,
+    },
+    UnpaidVaraPaid {
+        spender: ActorId,
+        vara: u128,
+    },
+    VaraDeferred {
+        campaign_id: CampaignId,
+        stage: u32,
+        spender: ActorId,
+        vara: u128,
+    },
+}
+
+#[derive(Clone)]
+pub struct L2eTop(());
+
 impl L2eTop {
-    pub fn new(erc20: ActorId, erc721: ActorId) -> Self {
+    pub fn seed(erc20: ActorId, erc721: ActorId) -> Self {
         // only test environment
         // let default_bal: Vec<(AccountId, Balance, Balance)> = Vec::new();
         let default_bal_map = HashMap::new();
@@ -55,138 +236,171 @@ impl L2eTop {
         let default_nft_map = HashMap::new();
         // default_nft_map.insert(AccountId::from([0x00; 32]), &default_nft);
 
-        let mut erc20_address: Vec<ActorId> = Vec::new();
+        // metadata of constructor contracts is unknown until they answer a query,
+        // symbol and decimals are left empty until admin calls refresh_contract.
+        let mut contracts = HashMap::new();
         // 5CvYPNqkGfBHnXg4dcq64wH8UPzErkgLi4AxNuvk5kU6PonN
         // let def_erc20 = "5CvYPNqkGfBHnXg4dcq64wH8UPzErkgLi4AxNuvk5kU6PonN";
         // let def_erc20_address = AccountId32::from_ss58check(0def_erc20).unwrap();
-        erc20_address.push(erc20);
-        let mut erc721_address: Vec<ActorId> = Vec::new();
+        contracts.insert(
+            erc20,
+            ContractInfo {
+                kind: ContractKind::Vft,
+                symbol: String::new(),
+                decimals: 0,
+                enabled: true,
+            },
+        );
         // let def_erc721 = "5HiNjbd3BAVwbGFwtZgsZcphi3HZv1yFZqAVWQwASjEARzuC";
         // let def_erc721_address = AccountId32::from_ss58check(def_erc20).unwrap();
-        erc721_address.push(erc721);
-
-        let token_id_num = U256::from(10000);
+        contracts.insert(
+            erc721,
+            ContractInfo {
+                kind: ContractKind::Vnft,
+                symbol: String::new(),
+                decimals: 0,
+                enabled: true,
+            },
+        );
 
         let self_address = msg::source();
-        let mut admin_address: Vec<ActorId> = Vec::new();
-        admin_address.push(self_address);
 
-        let mut auth_token_owner: Vec<ActorId> = Vec::new();
-        auth_token_owner.push(self_address);
+        unsafe {
+            L2E_STORAGE = Some(L2eStorage {
+                balances: default_bal_map,
+                nfts: default_nft_map,
+                contracts,
+                l2e_erc20: erc20,
+                l2e_erc721: erc721,
+                admins: [self_address].into(),
+                motivators: [self_address].into(),
+                verifiers: [self_address].into(),
+                pausers: [self_address].into(),
+                ..Default::default()
+            });
+        };
+        Self(())
+    }
 
-        Self {
-            balances: default_bal_map,
-            nfts: default_nft_map,
-            erc20_address,
-            erc721_address,
-            token_id_num,
-            admin_address,
-            auth_token_owner,
-        }
+    pub fn get_mut(&mut self) -> &'static mut L2eStorage {
+        unsafe { L2E_STORAGE.as_mut().expect("L2eTop is not initialized") }
     }
 
-    // Service's method (command)
-    pub fn get_erc20_address(&self) -> Vec<ActorId> {
-        gstd::debug!("erc20_address: {:?}", self.erc20_address.clone());
-        self.erc20_address.clone()
+    pub fn get(&self) -> &'static L2eStorage {
+        unsafe { L2E_STORAGE.as_ref().expect("L2eTop is not initialized") }
+    }
+}
+
+#[service(events = Event)]
+impl L2eTop {
+    pub fn new() -> Self {
+        Self(())
     }
 
-    pub fn get_erc721_address(&self) -> Vec<ActorId> {
-        gstd::debug!("erc721_address: {:?}", self.erc721_address.clone());
-        self.erc721_address.clone()
+    // Service's method (command)
+    pub fn get_erc20_address(&self) -> Result<Vec<ActorId>, L2eError> {
+        gstd::debug!("erc20_address: {:?}", self.get().contracts);
+        Ok(self.contract_addresses(ContractKind::Vft))
     }
 
-    pub fn get_admin_address(&self) -> Vec<ActorId> {
-        gstd::debug!("erc721_address: {:?}", self.admin_address.clone());
-        self.admin_address.clone()
+    pub fn get_erc721_address(&self) -> Result<Vec<ActorId>, L2eError> {
+        gstd::debug!("erc721_address: {:?}", self.get().contracts);
+        Ok(self.contract_addresses(ContractKind::Vnft))
     }
 
-    pub fn get_auth_token_owner_address(&self) -> Vec<ActorId> {
-        gstd::debug!("erc721_address: {:?}", self.auth_token_owner.clone());
-        self.auth_token_owner.clone()
+    pub fn get_contract(&self, address: ActorId) -> Result<ContractInfo, L2eError> {
+        self.get()
+            .contracts
+            .get(&address)
+            .cloned()
+            .ok_or(L2eError::UnknownContract)
     }
 
     // AccountId: spender address
     // bool: if bool is true, then spender already claim nft.
-    pub fn get_all_spender_claimed_for_owner(&self) -> Option<Vec<(ActorId, TokenId, bool)>> {
+    pub fn get_all_spender_claimed_for_owner(
+        &self,
+    ) -> Result<Vec<(ActorId, TokenId, bool)>, L2eError> {
         let owner = msg::source();
         gstd::debug!(
             "get_all_spender_claimed_for_owner self.nfts: {:?}",
-            self.nfts
+            self.get().nfts
         );
         // let mut claimed_reault: (Vec<(AccountId, TokenId, bool)>, Vec<(AccountId, Balance, Balance)>);
-        if self.nfts.contains_key(&owner) {
-            let spender_nftid_claim = self.nfts.get(&owner);
+        if self.get().nfts.contains_key(&owner) {
+            let spender_nftid_claim = self.get().nfts.get(&owner);
             gstd::debug!("spender_nftid_claim: {:?}", spender_nftid_claim);
             if let Some(vecs) = spender_nftid_claim {
                 let mut result_vecs = Vec::new();
-                for vec in vecs {
-                    result_vecs.push(*vec);
+                for &(spender, token_id, _, claimed) in vecs {
+                    result_vecs.push((spender, token_id, claimed));
                 }
-                return Some(result_vecs);
+                return Ok(result_vecs);
             }
         }
 
         gstd::debug!("get_all_spender_claimed_for_owner over");
-        None
+        Ok(Vec::new())
     }
 
     // AccountId: owner address Vec<(AccountId, Balance, Balance)>
-    pub fn get_all_owner_rewards_for_spender(&self) -> Option<Vec<(ActorId, u128, U256)>> {
+    pub fn get_all_owner_rewards_for_spender(
+        &self,
+    ) -> Result<Vec<(ActorId, u128, U256)>, L2eError> {
         let spender = msg::source();
-        if self.balances.contains_key(&spender) {
-            let owner_address = self.balances.get(&spender);
+        if self.get().balances.contains_key(&spender) {
+            let owner_address = self.get().balances.get(&spender);
             if let Some(vecs) = owner_address {
-                return Some(vecs.iter().map(|&v| (v.0, v.1, v.2)).collect());
+                return Ok(vecs.iter().map(|&v| (v.0, v.1, v.2)).collect());
             }
         }
 
         gstd::debug!("get_all_owner_rewards_for_spender over");
-        None
+        Ok(Vec::new())
     }
 
-    pub fn get_spender_vara_allowances(&self, owner: ActorId) -> Option<u128> {
+    pub fn get_spender_vara_allowances(&self, owner: ActorId) -> Result<u128, L2eError> {
         let spender = msg::source();
 
-        if self.balances.contains_key(&spender) {
-            let balances = self.balances.get(&spender);
+        if self.get().balances.contains_key(&spender) {
+            let balances = self.get().balances.get(&spender);
 
             if let Some(vec) = balances {
                 let value = vec.iter().find(|&v| v.0 == owner);
                 if let Some(v) = value {
-                    return Some(v.1);
+                    return Ok(v.1);
                 }
             }
         }
-        None
+        Err(L2eError::NoExistVaraApprove)
     }
 
     pub async fn get_spender_token_allowances(
         &self,
         owner: ActorId,
-        erc20_num: u32,
-    ) -> Option<U256> {
+        erc20: ActorId,
+    ) -> Result<U256, L2eError> {
         let spender = msg::source();
 
-        let mut current_erc20 = self.erc20_address[0];
+        self.ensure_contract(erc20, ContractKind::Vft)?;
+        let current_erc20 = erc20;
 
-        if (self.erc20_address.len() as u32)
-            > erc20_num.checked_add(1).expect("Failed to add erc20_num")
-        {
-            current_erc20 = self.erc20_address[erc20_num as usize];
-        }
+        // tokens are approved in a single erc20, claims pull them from owner through this program
+        if self.get().approved_erc20.get(&(owner, spender)) == Some(&current_erc20) {
+            let (_, approved_tokens) = self
+                .approved_value(owner, spender)
+                .ok_or(L2eError::NoExistTokenApprove)?;
 
-        if self.balances.contains_key(&spender) {
             // cross contract call
-            let call_payload = vft::io::Allowance::encode_call(owner, spender);
+            let call_payload = vft::io::Allowance::encode_call(owner, exec::program_id());
             let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc20, call_payload, 0, 0)
-                .expect("Failed to send_bytes_for_reply")
+                .map_err(|_| L2eError::TransactionFailed)?
                 .await
-                .expect("Failed to send_bytes_for_reply await");
+                .map_err(|_| L2eError::TransactionFailed)?;
             let reply =
                 <vft::io::Allowance as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
-                    .expect("Failed to decode_reply");
-            return Some(reply);
+                    .map_err(|_| L2eError::TransactionFailed)?;
+            return Ok(approved_tokens.min(reply));
 
             // let balances = build_call::<DefaultEnvironment>()
             //     // ERC20 address, gas_limit must be some value when in mainnet
@@ -210,173 +424,68 @@ impl L2eTop {
             //     return Some(value);
             // }
         }
-        None
+        Err(L2eError::NoExistTokenApprove)
     }
 
-    pub fn get_spender_nft_allowances(&self, owner: ActorId) -> Option<TokenId> {
+    pub fn get_spender_nft_allowances(&self, owner: ActorId) -> Result<TokenId, L2eError> {
         let spender = msg::source();
-        gstd::debug!("get_spender_nft_allowances self.nfts: {:?}", self.nfts);
-        if self.nfts.contains_key(&owner) {
-            let nfts = self.nfts.get(&owner);
+        gstd::debug!(
+            "get_spender_nft_allowances self.nfts: {:?}",
+            self.get().nfts
+        );
+        if self.get().nfts.contains_key(&owner) {
+            let nfts = self.get().nfts.get(&owner);
             gstd::debug!("nfts: {:?}", nfts);
             if let Some(vec) = nfts {
                 let value = vec.iter().find(|&v| v.0 == spender);
                 gstd::debug!("value: {:?}", value);
                 if let Some(v) = value {
-                    return Some(v.1);
+                    return Ok(v.1);
                 }
             }
         }
-        None
+        Err(L2eError::NoExistNFTApprove)
     }
 
     pub async fn approve_balances(
         &mut self,
         spender: ActorId,
-        erc20_num: u32,
+        erc20: ActorId,
         vara_value: U256,
         token_value: U256,
-    ) -> Option<(u128, U256)> {
+    ) -> Result<(u128, U256), L2eError> {
         let owner = msg::source();
-        let mut current_value: u128 = 0;
-        // vara_value should be transfer value, msg::value() acutal value.
-        // frontend control vara_value == msg::value()
-        if vara_value > U256::from(0) {
-            current_value = msg::value();
-        }
-        gstd::debug!("current_value-{}", current_value);
-        gstd::debug!("token_value:{}", token_value);
-        if token_value > U256::from(0) {
-            gstd::debug!("token_value>0");
-            let mut current_erc20 = self.erc20_address[0];
-
-            if (self.erc20_address.len() as u32)
-                > erc20_num.checked_add(1).expect("Failed to add erc20_num")
-            {
-                current_erc20 = self.erc20_address[erc20_num as usize];
-            } else {
-                // check auth_token_owner role
-                if !self.auth_token_owner.contains(&owner) {
-                    let _ = self.notify_on(Event::NoAuthToApproveL2EToken);
-                    panic!("NoAuthToApproveL2EToken");
-                }
-            }
-
-            gstd::debug!("current_erc20:{:?}", current_erc20);
-            gstd::debug!("current_erc20:{:?}", self.erc20_address);
-
-            // cross contract call
-            let call_payload = vft::io::BalanceOf::encode_call(owner);
-            let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc20, call_payload, 0, 0)
-                .expect("Failed to send_bytes_for_reply")
-                .await
-                .expect("Failed to send_bytes_for_reply await");
-            let result_balance_of =
-                <vft::io::BalanceOf as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes);
-
-            // let result_balance_of = build_call::<DefaultEnvironment>()
-            //     // ERC20 address, gas_limit must be some value when in mainnet
-            //     .call(current_erc20)
-            //     .call_v1()
-            //     .gas_limit(0)
-            //     .transferred_value(0)
-            //     .exec_input(
-            //         ExecutionInput::new(Selector::new(ink::selector_bytes!("balanceOf")))
-            //             .push_arg(owner),
-            //     )
-            //     .returns::<Balance>()
-            //     .try_invoke()
-            //     .expect("Failed to get result_balance_of");
-            //     // .map_err(|e| format!("approve_balances failed: {:?}", e));
-
-            gstd::debug!("result_balance_of error:{:?}", result_balance_of);
-
-            if let Ok(balance_of) = result_balance_of {
-                if token_value > balance_of / 1000 {
-                    let _ = self.notify_on(Event::InsufficientOwnerDepositTokens);
-                    panic!("InsufficientOwnerDepositTokens");
-                }
-            }
-
-            // cross contract call
-            let call_payload = vft::io::Approve::encode_call(spender, token_value);
-            let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc20, call_payload, 0, 0)
-                .expect("Failed to send_bytes_for_reply")
-                .await
-                .expect("Failed to send_bytes_for_reply await");
-            let result_approve =
-                <vft::io::Approve as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
-                    .expect("Failed to decode_reply");
-
-            // let result_approve = build_call::<DefaultEnvironment>()
-            //     // ERC20 address, gas_limit must be some value when in mainnet
-            //     .call(current_erc20)
-            //     .call_v1()
-            //     .gas_limit(0)
-            //     .transferred_value(0)
-            //     .exec_input(
-            //         ExecutionInput::new(Selector::new(ink::selector_bytes!("approve")))
-            //             .push_arg(spender)
-            //             .push_arg(token_value),
-            //     )
-            //     .returns::<Result<(), Error>>()
-            //     .try_invoke()
-            //     .map_err(|e| format!("approve_balances failed: {:?}", e));
-
-            gstd::debug!("result_approve error:{:?}", result_approve);
-        }
-        gstd::debug!("token_value  over");
-        if self.balances.contains_key(&spender) {
-            gstd::debug!("self.balances.contains(spender)");
-            let owner_value = self
-                .balances
-                .get_mut(&spender)
-                .expect("failed to take owner value");
-            gstd::debug!("owner_value::{:?}", owner_value);
-            if owner_value.iter().any(|&(o, _, _)| o == owner) {
-                let _ = self.notify_on(Event::BalancesAlreadyApproved);
-                panic!("BalancesAlreadyApproved");
-            }
-
-            owner_value.push((owner, current_value, token_value));
-            // self.balances.insert(spender, owner_value.clone());
-        } else {
-            let mut owner_value = Vec::new();
-            owner_value.push((owner, current_value, token_value));
-            self.balances.insert(spender, owner_value);
-            gstd::debug!("owner_value--{:?}", owner_value);
+        let result = self
+            .try_approve_balances(owner, spender, erc20, vara_value, token_value)
+            .await;
+        // error reply doesn't return attached value, give it back to owner
+        if result.is_err() {
+            self.refund_vara(owner, msg::value())?;
         }
-        gstd::debug!("owner_value--over");
-        Some((current_value, token_value))
+        result
     }
 
-    pub async fn mint_approve_nft(&mut self, erc721_num: u32, spender: ActorId) -> bool {
+    pub async fn mint_approve_nft(
+        &mut self,
+        erc721: ActorId,
+        spender: ActorId,
+    ) -> Result<TokenId, L2eError> {
         let owner = msg::source();
 
-        // tokenid u32
-        self.token_id_num = self
-            .token_id_num
-            .checked_add(U256::from(1))
-            .expect("Failed to create token_id");
-        let token_id: TokenId = self.token_id_num;
-
-        let mut current_erc721 = self.erc721_address[0];
-        if (self.erc721_address.len() as u32)
-            > erc721_num.checked_add(1).expect("Failed to add erc721_num")
+        self.ensure_not_paused(Operation::MintApproveNft)?;
+        self.ensure_contract(erc721, ContractKind::Vnft)?;
+        let current_erc721 = erc721;
+        // check motivator or verifier role
+        if current_erc721 == self.get().l2e_erc721
+            && !self.get().motivators.contains(&owner)
+            && !self.get().verifiers.contains(&owner)
         {
-            current_erc721 = self.erc721_address[erc721_num as usize];
-        } else {
-            // check auth_token_owner role
-            if !self.auth_token_owner.contains(&owner) {
-                let _ = self.notify_on(Event::NoAuthToMintL2ENFT);
-                panic!("NoAuthToMintL2ENFT");
-            }
+            return Err(L2eError::NoAuthToMintL2ENFT);
         }
 
         gstd::debug!("current_erc721:{:?}", current_erc721);
-        gstd::debug!("token_id:{:?}", token_id);
         // cross contract call
-        // call ERC721 mint function
+        // call ERC721 mint function, token id is assigned by the VNFT contract
         let tm = TokenMetadata {
             name: "L2E".to_string(),
             description: "L2E.TOP".to_string(),
@@ -385,12 +494,12 @@ impl L2eTop {
         };
         let call_payload = vnft::io::Mint::encode_call(exec::program_id(), tm);
         let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc721, call_payload, 0, 0)
-            .expect("Failed to send_bytes_for_reply")
+            .map_err(|_| L2eError::TransactionFailed)?
             .await
-            .expect("Failed to send_bytes_for_reply await");
-        let mint_nft =
+            .map_err(|_| L2eError::TransactionFailed)?;
+        let token_id: TokenId =
             <vnft::io::Mint as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
-                .expect("Failed to decode_reply");
+                .map_err(|_| L2eError::TransactionFailed)?;
 
         // let mint_approve_nft = build_call::<DefaultEnvironment>()
         //     // ERC721
@@ -407,18 +516,18 @@ impl L2eTop {
         //     // .map_err(|_| Error::FailedMintNFT)?;
         //     .map_err(|e| format!("mint_approve_nft failed: {:?}", e));
 
-        gstd::debug!("mint_approve_nft error:{:?}", mint_nft);
+        gstd::debug!("token_id:{:?}", token_id);
 
         // cross contract call
         // call ERC721 approve function
         let call_payload = vnft::io::Approve::encode_call(spender, token_id);
         let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc721, call_payload, 0, 0)
-            .expect("Failed to send_bytes_for_reply")
+            .map_err(|_| L2eError::TransactionFailed)?
             .await
-            .expect("Failed to send_bytes_for_reply await");
+            .map_err(|_| L2eError::TransactionFailed)?;
         let approve_nft =
             <vnft::io::Approve as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
-                .expect("Failed to decode_reply");
+                .map_err(|_| L2eError::TransactionFailed)?;
 
         // let approve_nft = build_call::<DefaultEnvironment>()
         //     // vara ERC721 address
@@ -438,74 +547,125 @@ impl L2eTop {
         gstd::debug!("approve_nft error:{:?}", approve_nft);
 
         // store nft tokenid and spender address
-        if self.nfts.contains_key(&owner) {
+        if self.get().nfts.contains_key(&owner) {
             let nft_tokens = self
+                .get_mut()
                 .nfts
                 .get_mut(&owner)
                 .expect("Failed to get (spender, token_id)");
-            nft_tokens.push((spender, token_id, false));
+            nft_tokens.push((spender, token_id, current_erc721, false));
             // self.nfts.insert(owner, nft_tokens.clone());
         } else {
             let mut spender_nftid_claim = Vec::new();
-            spender_nftid_claim.push((spender, token_id, false));
+            spender_nftid_claim.push((spender, token_id, current_erc721, false));
 
-            self.nfts.insert(owner, spender_nftid_claim);
+            self.get_mut().nfts.insert(owner, spender_nftid_claim);
         }
         gstd::debug!("mint_approve_nft--over");
 
-        true
+        self.notify_on(Event::NftMinted {
+            owner,
+            spender,
+            token_id,
+            collection: current_erc721,
+        })
+        .expect("Notification Error");
+
+        Ok(token_id)
     }
 
-    // spender claim balances to his account
+    // spender claim balances of a campaign stage to his account
+    // stage N+1 can be claimed only after stage N is claimed.
     // claim vara
     // claim token, frontend should be transfer 0.000000000001 Unit represent 1 Token.
-    pub fn transfer_balances_from(
+    pub async fn transfer_balances_from(
         &mut self,
-        owner: ActorId,
-        vara_value: u128,
-        token_value: U256,
-        erc20_num: u32,
-    ) -> bool {
+        campaign_id: CampaignId,
+        stage: u32,
+    ) -> Result<(), L2eError> {
         let spender = msg::source();
 
+        self.ensure_not_paused(Operation::TransferBalancesFrom)?;
+
+        let Some(campaign) = self.get().campaigns.get(&campaign_id) else {
+            return Err(L2eError::NoExistCampaign);
+        };
+
+        let block_height = exec::block_height();
+        if block_height < campaign.start_block || block_height > campaign.end_block {
+            return Err(L2eError::CampaignNotActive);
+        }
+
+        self.ensure_contract(campaign.erc20, ContractKind::Vft)?;
+        let current_erc20 = campaign.erc20;
+
+        let Some(current_stage) = campaign.stages.get(stage as usize) else {
+            return Err(L2eError::NoExistStage);
+        };
+
+        // stage claim of spender is already waiting for replies
+        if self.get().pending_claims.contains(&(campaign_id, spender)) {
+            return Err(L2eError::ClaimPending);
+        }
+
+        let claimed_count = self
+            .get()
+            .claimed_stages
+            .get(&(campaign_id, spender))
+            .copied()
+            .unwrap_or_default();
+        if stage < claimed_count {
+            return Err(L2eError::StageAlreadyClaimed);
+        }
+        if stage > claimed_count {
+            return Err(L2eError::PreviousStageNotClaimed);
+        }
+
+        let owner = campaign.motivator;
+        let vara_value = current_stage.vara_value;
+        let token_value = current_stage.token_value;
+        gstd::debug!("campaign_id:{}---stage:{}", campaign_id, stage);
+
         // check nft authorization
-        if self.nfts.contains_key(&owner) {
+        if self.get().nfts.contains_key(&owner) {
             let spender_nftid_claim = self
+                .get()
                 .nfts
                 .get(&owner)
                 .expect("failed to get nfts spender_value");
             gstd::debug!("transfer_balances_from: {:?}", spender_nftid_claim);
-            let spender_value = spender_nftid_claim.iter().find(|&x| x.0 == spender);
+            // certificate must be claimed from the campaign collection
+            let spender_value = spender_nftid_claim
+                .iter()
+                .find(|&x| x.0 == spender && x.2 == campaign.erc721 && x.3);
             gstd::debug!("spender_value: {:?}", spender_value);
-            if let Some(&(_, _, claimed)) = spender_value {
-                if !claimed {
-                    let _ = self.notify_on(Event::NoClaimedNFT);
-                    panic!("NoClaimedNFT");
+            if spender_value.is_none() {
+                if spender_nftid_claim
+                    .iter()
+                    .any(|x| x.0 == spender && x.2 == campaign.erc721)
+                {
+                    return Err(L2eError::NoClaimedNFT);
                 }
-            } else {
-                let _ = self.notify_on(Event::NoExistNFTApprove);
-                panic!("NoExistNFTApprove");
+                return Err(L2eError::NoExistNFTApprove);
             }
         } else {
             gstd::debug!("nfts not contains owner");
-            let _ = self.notify_on(Event::NoExistNFTApprove);
-            panic!("NoExistNFTApprove");
+            return Err(L2eError::NoExistNFTApprove);
         }
         gstd::debug!("check nft authorization--over");
 
-        if !self.balances.contains_key(&spender) {
+        if !self.get().balances.contains_key(&spender) {
             // check vara authorization
             if vara_value > 0 {
-                let _ = self.notify_on(Event::NoExistVaraApprove);
-                panic!("NoExistVaraApprove");
+                return Err(L2eError::NoExistVaraApprove);
             }
             // check vara authorization
             if token_value > U256::from(0) {
-                let _ = self.notify_on(Event::NoExistTokenApprove);
-                panic!("NoExistTokenApprove");
+                return Err(L2eError::NoExistTokenApprove);
             }
         } else {
             let owner_vara_token = self
+                .get()
                 .balances
                 .get(&spender)
                 .expect("failed to get balances owner_value");
@@ -513,8 +673,7 @@ impl L2eTop {
             let owner_value = owner_vara_token.iter().find(|&x| x.0 == owner);
 
             if owner_value.is_none() {
-                let _ = self.notify_on(Event::NoExistVaraApprove);
-                panic!("NoExistVaraApprove");
+                return Err(L2eError::NoExistVaraApprove);
             }
             gstd::debug!("owner_value: {:?}", owner_value);
             gstd::debug!(
@@ -525,139 +684,274 @@ impl L2eTop {
                 "owner_value 2: {:?}",
                 owner_value.expect("failed to get owner value").2
             );
-            if vara_value > 0
-                && owner_value.expect("failed to get owner value").1 < vara_value
-            {
-                let _ = self.notify_on(Event::InsufficientApproveVaras);
-                panic!("InsufficientApproveVaras");
-                // transfer vara to spender account, gas fee will be deducted from spender account.
-            } else if gstd::msg::send_with_gas(spender, Event::TransactionAlreadySend, 1, vara_value).is_err() {
-                let _ = self.notify_on(Event::TransactionFailed);
-                panic!("TransactionFailed");
+            if vara_value > 0 && owner_value.expect("failed to get owner value").1 < vara_value {
+                return Err(L2eError::InsufficientApproveVaras);
             }
 
-            let mut current_erc20 = self.erc20_address[0];
-
-            if (self.erc20_address.len() as u32)
-                > erc20_num.checked_add(1).expect("Failed to add erc20_num")
-            {
-                current_erc20 = self.erc20_address[erc20_num as usize];
-            }
             gstd::debug!("vara_value:{}---token_value:{}", vara_value, token_value);
             if token_value > U256::from(0)
                 && owner_value.expect("failed to get value").2 < token_value
             {
                 // if InsufficientApproveTokens is true, frontend should not call erc20 transferFrom function.
-                let _ = self.notify_on(Event::InsufficientApproveTokens);
-                panic!("InsufficientApproveTokens");
+                return Err(L2eError::InsufficientApproveTokens);
+            }
+            // tokens must be approved in the campaign erc20
+            if token_value > U256::from(0)
+                && self.get().approved_erc20.get(&(owner, spender)) != Some(&current_erc20)
+            {
+                return Err(L2eError::NoExistTokenApprove);
             }
-
-            // // spender claim straight through ERC20 claim token
-            // let _ = build_call::<DefaultEnvironment>()
-            //     .call(current_erc20)
-            //     .call_v1()
-            //     .gas_limit(0)
-            //     .transferred_value(0)
-            //     .exec_input(
-            //         ExecutionInput::new(Selector::new(ink::selector_bytes!("transferFrom")))
-            //             .push_arg(owner)
-            //             .push_arg(spender)
-            //             .push_arg(token),
-            //     )
-            //     .returns::<Vec<u8>>()
-            //     .try_invoke()
-            //     .map_err(|_| Error::TransactionTokenCallFailed)?;
         }
 
         gstd::debug!("check vara authorization--over");
 
-        let owner_vara_token = self
+        let Some((_, vara_v, token_v)) = self
+            .get_mut()
             .balances
             .get_mut(&spender)
-            .expect("failed to take owner value");
-        // subtract approve value
-        for (k, vara_v, token_v) in owner_vara_token.iter_mut() {
-            if k == &owner {
-                *vara_v = (*vara_v)
-                    .checked_sub(vara_value)
-                    .expect("subtract transfer vara failed");
-
-                *token_v = (*token_v)
-                    .checked_sub(token_value)
-                    .expect("subtract transfer token failed");
-                break;
-            }
-        }
-        // self.balances.insert(spender, owner_vara_token.clone());
+            .and_then(|vec| vec.iter_mut().find(|(o, _, _)| *o == owner))
+        else {
+            return Err(L2eError::NoExistVaraApprove);
+        };
+        let remaining_vara = vara_v
+            .checked_sub(vara_value)
+            .ok_or(L2eError::InsufficientApproveVaras)?;
+        let remaining_tokens = token_v
+            .checked_sub(token_value)
+            .ok_or(L2eError::InsufficientApproveTokens)?;
+
+        // spender claim straight through VFT claim token, owner must approve this program before.
+        // cross contract call, reply is awaited after approve value is reserved
+        let token_reply = if token_value > U256::from(0) {
+            gstd::debug!("current_erc20:{:?}", current_erc20);
+            let call_payload = vft::io::TransferFrom::encode_call(owner, spender, token_value);
+            Some(
+                gstd::msg::send_bytes_for_reply(current_erc20, call_payload, 0, 0)
+                    .map_err(|_| L2eError::TransactionFailed)?,
+            )
+        } else {
+            None
+        };
+
+        // subtract approve value before cross contract call, restored if token transfer failed
+        *vara_v = remaining_vara;
+        *token_v = remaining_tokens;
         gstd::debug!(
             "transfer_balances_from over owner_vara_token::{:?}",
-            owner_vara_token
+            self.get().balances.get(&spender)
         );
 
-        true
+        self.get_mut()
+            .claimed_stages
+            .insert((campaign_id, spender), claimed_count + 1);
+        self.get_mut().pending_claims.insert((campaign_id, spender));
+
+        if let Some(reply) = token_reply {
+            let transferred = match reply.await {
+                Ok(reply_bytes) => {
+                    <vft::io::TransferFrom as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
+                        .unwrap_or_default()
+                }
+                Err(e) => {
+                    gstd::debug!("transfer_from error:{:?}", e);
+                    false
+                }
+            };
+
+            if !transferred {
+                // state above is already committed by await, compensate reserved approve value
+                self.rollback_claim(campaign_id, spender, claimed_count);
+                self.restore_approval(owner, spender, vara_value, token_value)?;
+
+                return Err(L2eError::TransactionFailed);
+            }
+        }
+
+        // transfer vara to spender account only after tokens are received.
+        let mut vara_deferred = false;
+        if vara_value > 0 {
+            let paid = match gstd::msg::send_for_reply(
+                spender,
+                Event::TransactionAlreadySend,
+                vara_value,
+                0,
+            ) {
+                Ok(reply) => reply.await.is_ok(),
+                Err(e) => {
+                    gstd::debug!("send vara error:{:?}", e);
+                    false
+                }
+            };
+
+            if !paid {
+                if token_value == U256::zero() {
+                    // nothing is paid, vara goes back to approve value and stage can be claimed.
+                    self.rollback_claim(campaign_id, spender, claimed_count);
+                    self.restore_approval(owner, spender, vara_value, U256::zero())?;
+
+                    return Err(L2eError::TransactionFailed);
+                }
+                // stage stays claimed since tokens are already received,
+                // vara is kept for spender to take by claim_unpaid_vara.
+                self.add_unpaid_vara(spender, vara_value);
+                vara_deferred = true;
+            }
+        }
+        self.get_mut()
+            .pending_claims
+            .remove(&(campaign_id, spender));
+
+        self.notify_on(Event::RewardClaimed {
+            campaign_id,
+            stage,
+            owner,
+            spender,
+            vara: vara_value,
+            tokens: token_value,
+        })
+        .expect("Notification Error");
+        if vara_deferred {
+            self.notify_on(Event::VaraDeferred {
+                campaign_id,
+                stage,
+                spender,
+                vara: vara_value,
+            })
+            .expect("Notification Error");
+        }
+
+        Ok(())
     }
 
-    // spender claim nft to his account
-    pub fn transfer_nft_from(&mut self, owner: ActorId, erc721_num: u32) -> bool {
+    // spender takes vara of claimed stages that failed to be sent by transfer_balances_from
+    pub async fn claim_unpaid_vara(&mut self) -> Result<u128, L2eError> {
         let spender = msg::source();
 
-        if !self.nfts.contains_key(&owner) {
-            let _ = self.notify_on(Event::NoExistNFTApprove);
-            panic!("NoExistNFTApprove");
+        let Some(vara_value) = self.get_mut().unpaid_vara.remove(&spender) else {
+            return Err(L2eError::NoUnpaidVara);
+        };
+
+        let paid = match gstd::msg::send_for_reply(
+            spender,
+            Event::TransactionAlreadySend,
+            vara_value,
+            0,
+        ) {
+            Ok(reply) => reply.await.is_ok(),
+            Err(e) => {
+                gstd::debug!("send vara error:{:?}", e);
+                false
+            }
+        };
+        if !paid {
+            self.add_unpaid_vara(spender, vara_value);
+            return Err(L2eError::TransactionFailed);
         }
 
-        let spender_nftid_claim = self.nfts.get(&owner).expect("failed to get owner_value");
+        self.notify_on(Event::UnpaidVaraPaid {
+            spender,
+            vara: vara_value,
+        })
+        .expect("Notification Error");
 
-        let spender_value = spender_nftid_claim.iter().find(|&x| x.0 == spender);
+        Ok(vara_value)
+    }
 
-        if spender_value.is_none() {
-            let _ = self.notify_on(Event::NoExistNFTApprove);
-            panic!("NoExistNFTApprove");
+    pub fn get_unpaid_vara(&self, spender: ActorId) -> Result<u128, L2eError> {
+        Ok(self
+            .get()
+            .unpaid_vara
+            .get(&spender)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    // spender claim nft to his account
+    pub async fn transfer_nft_from(
+        &mut self,
+        owner: ActorId,
+        erc721: ActorId,
+    ) -> Result<(), L2eError> {
+        let spender = msg::source();
+
+        self.ensure_not_paused(Operation::TransferNftFrom)?;
+
+        if !self.get().nfts.contains_key(&owner) {
+            return Err(L2eError::NoExistNFTApprove);
         }
 
-        let token_id = spender_value.expect("failed to get spender value").1;
+        let spender_nftid_claim = self
+            .get()
+            .nfts
+            .get(&owner)
+            .expect("failed to get owner_value");
+
+        // token id is only meaningful in the collection it was minted in
+        let spender_value = spender_nftid_claim
+            .iter()
+            .find(|&x| x.0 == spender && x.2 == erc721);
+
+        let Some(&(_, token_id, collection, claimed)) = spender_value else {
+            return Err(L2eError::NoExistNFTApprove);
+        };
+        if claimed {
+            return Err(L2eError::AlreadyClaimedNFT);
+        }
         gstd::debug!(
             "transfer_nft_from spender_token_id_vec::{:?}",
             spender_nftid_claim
         );
-        if token_id == U256::from(0) {
-            let _ = self.notify_on(Event::InsufficientApproveTokens);
-            panic!("InsufficientApproveTokens");
-        }
         gstd::debug!("token_id: {:?}", token_id);
-        let mut current_erc721 = self.erc721_address[0];
-        if (self.erc721_address.len() as u32)
-            > erc721_num.checked_add(1).expect("Failed to add erc721_num")
+        self.ensure_contract(collection, ContractKind::Vnft)?;
+        let current_erc721 = collection;
+
+        // spender claim straight through VNFT claim nft, nft is minted to this program.
+        // cross contract call
+        let call_payload =
+            vnft::io::TransferFrom::encode_call(exec::program_id(), spender, token_id);
+        let transfer_nft = match gstd::msg::send_bytes_for_reply(current_erc721, call_payload, 0, 0)
+            .map_err(|_| L2eError::TransactionFailed)?
+            .await
         {
-            current_erc721 = self.erc721_address[erc721_num as usize];
+            Ok(reply_bytes) => {
+                <vnft::io::TransferFrom as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
+                    .is_ok()
+            }
+            Err(e) => {
+                gstd::debug!("transfer_nft error:{:?}", e);
+                false
+            }
+        };
+        if !transfer_nft {
+            return Err(L2eError::TransactionFailed);
         }
 
-        // // spender claim straight through ERC721 claim nft
-        // let transfer_nft = build_call::<DefaultEnvironment>()
-        //     .call(current_erc721)
-        //     .call_v1()
-        //     .gas_limit(0)
-        //     .transferred_value(0)
-        //     .exec_input(
-        //         ExecutionInput::new(Selector::new(ink::selector_bytes!("transferFrom")))
-        //             .push_arg(self.admin_address[0])
-        //             .push_arg(spender)
-        //             .push_arg(token_id),
-        //     )
-        //     .returns::<()>()
-        //     .try_invoke()
-        //     // .map_err(|_| Error::TransactionNFTCallFailed)?;
-        //     .map_err(|e| format!("transfer_nft failed: {:?}", e));
-
-        // ink::env::debug_println!("transfer_nft error3:{:?}", transfer_nft);
+        // cross contract call
+        // make sure spender is the new owner before marking nft claimed
+        let call_payload = vnft::io::OwnerOf::encode_call(token_id);
+        let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc721, call_payload, 0, 0)
+            .map_err(|_| L2eError::TransactionFailed)?
+            .await
+            .map_err(|_| L2eError::TransactionFailed)?;
+        let nft_owner =
+            <vnft::io::OwnerOf as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
+                .map_err(|_| L2eError::TransactionFailed)?;
+        gstd::debug!("nft_owner:{:?}", nft_owner);
+        if nft_owner != spender {
+            return Err(L2eError::TransactionFailed);
+        }
 
         let spender_nftid_claim = self
+            .get_mut()
             .nfts
             .get_mut(&owner)
             .expect("failed to take owner value");
         // Set already claim nft to true
-        if let Some(index) = spender_nftid_claim.iter().position(|&x| x.0 == spender) {
-            spender_nftid_claim[index].2 = true;
+        if let Some(index) = spender_nftid_claim
+            .iter()
+            .position(|&x| x.0 == spender && x.1 == token_id && x.2 == current_erc721)
+        {
+            spender_nftid_claim[index].3 = true;
             // self.nfts.insert(owner, spender_nftid_claim.clone());
             gstd::debug!("Element found and modified: {:?}", spender_nftid_claim);
         } else {
@@ -668,9 +962,10 @@ impl L2eTop {
             "transfer_nft_from spender_nftid_claim::{:?}",
             spender_nftid_claim
         );
-        gstd::debug!("transfer_nft_from self.nfts:{:?}", self.nfts);
-        if self.nfts.contains_key(&owner) {
+        gstd::debug!("transfer_nft_from self.nfts:{:?}", self.get().nfts);
+        if self.get().nfts.contains_key(&owner) {
             let spender_nftid_claim = self
+                .get()
                 .nfts
                 .get(&owner)
                 .expect("Failed to try get_approved_balances_for_owner");
@@ -678,55 +973,692 @@ impl L2eTop {
         }
         gstd::debug!("transfer_nft_from over");
 
-        true
+        self.notify_on(Event::NftClaimed {
+            owner,
+            spender,
+            token_id,
+            collection: current_erc721,
+        })
+        .expect("Notification Error");
+
+        Ok(())
+    }
+
+    pub fn create_campaign(
+        &mut self,
+        erc20: ActorId,
+        erc721: ActorId,
+        start_block: u32,
+        end_block: u32,
+    ) -> Result<CampaignId, L2eError> {
+        let motivator = msg::source();
+        if !self.get().motivators.contains(&motivator) {
+            return Err(L2eError::NoAuthToCreateCampaign);
+        }
+
+        self.ensure_contract(erc20, ContractKind::Vft)?;
+        self.ensure_contract(erc721, ContractKind::Vnft)?;
+
+        if start_block > end_block {
+            return Err(L2eError::InvalidCampaignPeriod);
+        }
+
+        let storage = self.get_mut();
+        let campaign_id = storage.campaign_id_num;
+        storage.campaign_id_num = campaign_id
+            .checked_add(1)
+            .expect("Failed to create campaign_id");
+        storage.campaigns.insert(
+            campaign_id,
+            Campaign {
+                id: campaign_id,
+                motivator,
+                erc20,
+                erc721,
+                stages: Vec::new(),
+                start_block,
+                end_block,
+            },
+        );
+        gstd::debug!("create_campaign campaign_id:{}", campaign_id);
+
+        Ok(campaign_id)
+    }
+
+    // append next stage to campaign, returns stage index
+    pub fn add_stage(
+        &mut self,
+        campaign_id: CampaignId,
+        vara_value: u128,
+        token_value: U256,
+    ) -> Result<u32, L2eError> {
+        let Some(campaign) = self.get_mut().campaigns.get_mut(&campaign_id) else {
+            return Err(L2eError::NoExistCampaign);
+        };
+
+        if campaign.motivator != msg::source() {
+            return Err(L2eError::NoAuthToUpdateCampaign);
+        }
+
+        // every stage is paid from an approval, a stage without rewards has nothing to claim
+        if vara_value == 0 && token_value.is_zero() {
+            return Err(L2eError::EmptyStage);
+        }
+
+        campaign.stages.push(Stage {
+            vara_value,
+            token_value,
+        });
+        gstd::debug!("add_stage campaign:{:?}", campaign);
+
+        Ok((campaign.stages.len() - 1) as u32)
+    }
+
+    pub fn get_campaign(&self, campaign_id: CampaignId) -> Result<Campaign, L2eError> {
+        self.get()
+            .campaigns
+            .get(&campaign_id)
+            .cloned()
+            .ok_or(L2eError::NoExistCampaign)
+    }
+
+    // number of stages spender already claimed in campaign
+    pub fn get_claimed_stages(
+        &self,
+        campaign_id: CampaignId,
+        spender: ActorId,
+    ) -> Result<u32, L2eError> {
+        Ok(self
+            .get()
+            .claimed_stages
+            .get(&(campaign_id, spender))
+            .copied()
+            .unwrap_or_default())
+    }
+
+    // owner takes back approval of spender, remaining vara is returned to owner.
+    // remaining tokens are never pulled again, owner may lower its allowance to this program.
+    pub fn revoke_approval(&mut self, spender: ActorId) -> Result<u128, L2eError> {
+        let owner = msg::source();
+
+        let Some((vara_value, token_value)) = self.approved_value(owner, spender) else {
+            return Err(L2eError::NoExistApprove);
+        };
+        self.refund_vara(owner, vara_value)?;
+        self.remove_approval(owner, spender);
+
+        self.notify_on(Event::Refunded {
+            owner,
+            spender,
+            vara: vara_value,
+            tokens: token_value,
+        })
+        .expect("Notification Error");
+
+        Ok(vara_value)
+    }
+
+    // motivator cancels campaign and takes back all unclaimed approvals.
+    // approvals are shared by campaigns of the same motivator, so it's rejected while other
+    // campaigns of the motivator are live.
+    pub fn withdraw_unclaimed(&mut self, campaign_id: CampaignId) -> Result<u128, L2eError> {
+        let owner = msg::source();
+
+        let Some(campaign) = self.get().campaigns.get(&campaign_id) else {
+            return Err(L2eError::NoExistCampaign);
+        };
+        if campaign.motivator != owner {
+            return Err(L2eError::NoAuthToUpdateCampaign);
+        }
+
+        let block_height = exec::block_height();
+        let other_live =
+            self.get().campaigns.values().any(|c| {
+                c.id != campaign_id && c.motivator == owner && c.end_block >= block_height
+            });
+
+        if other_live {
+            return Err(L2eError::OtherCampaignsActive);
+        }
+
+        let refunds: Vec<(ActorId, u128, U256)> = self
+            .get()
+            .balances
+            .keys()
+            .filter_map(|&spender| {
+                let (vara_value, token_value) = self.approved_value(owner, spender)?;
+                Some((spender, vara_value, token_value))
+            })
+            .collect();
+        let total_vara: u128 = refunds.iter().map(|&(_, vara, _)| vara).sum();
+        self.refund_vara(owner, total_vara)?;
+
+        // campaign is cancelled, remaining stages can not be claimed anymore
+        self.get_mut().campaigns.remove(&campaign_id);
+        for (spender, vara_value, token_value) in refunds {
+            self.remove_approval(owner, spender);
+            self.notify_on(Event::Refunded {
+                owner,
+                spender,
+                vara: vara_value,
+                tokens: token_value,
+            })
+            .expect("Notification Error");
+        }
+        gstd::debug!("withdraw_unclaimed total_vara:{}", total_vara);
+
+        Ok(total_vara)
+    }
+
+    pub async fn register_vft(&mut self, address: ActorId) -> Result<(), L2eError> {
+        self.register_contract(address, ContractKind::Vft).await
+    }
+
+    pub async fn register_vnft(&mut self, address: ActorId) -> Result<(), L2eError> {
+        self.register_contract(address, ContractKind::Vnft).await
+    }
+
+    // disabled contract stays registered, but can't be used for new approvals and claims
+    pub fn disable_contract(&mut self, address: ActorId) -> Result<(), L2eError> {
+        self.ensure_is_admin()?;
+
+        let contract = self
+            .get_mut()
+            .contracts
+            .get_mut(&address)
+            .ok_or(L2eError::UnknownContract)?;
+        if !contract.enabled {
+            return Err(L2eError::ContractDisabled);
+        }
+        contract.enabled = false;
+
+        self.notify_on(Event::ContractDisabled { address })
+            .expect("Notification Error");
+
+        Ok(())
+    }
+
+    pub fn remove_contract(&mut self, address: ActorId) -> Result<(), L2eError> {
+        self.ensure_is_admin()?;
+
+        if self.get_mut().contracts.remove(&address).is_none() {
+            return Err(L2eError::UnknownContract);
+        }
+
+        self.notify_on(Event::ContractRemoved { address })
+            .expect("Notification Error");
+
+        Ok(())
+    }
+
+    // read metadata of a registered contract again, enabled flag is kept
+    pub async fn refresh_contract(&mut self, address: ActorId) -> Result<ContractInfo, L2eError> {
+        self.ensure_is_admin()?;
+
+        let kind = self
+            .get()
+            .contracts
+            .get(&address)
+            .ok_or(L2eError::UnknownContract)?
+            .kind;
+        let probed = Self::probe_contract(address, kind).await?;
+
+        // contract may be removed while waiting for replies
+        let contract = self
+            .get_mut()
+            .contracts
+            .get_mut(&address)
+            .ok_or(L2eError::UnknownContract)?;
+        contract.symbol = probed.symbol;
+        contract.decimals = probed.decimals;
+        let contract = contract.clone();
+
+        self.notify_on(Event::ContractRefreshed { address })
+            .expect("Notification Error");
+
+        Ok(contract)
+    }
+
+    pub fn grant_role(&mut self, role: Role, to: ActorId) -> Result<(), L2eError> {
+        self.ensure_is_admin()?;
+        // new admins go through propose_admin and accept_admin
+        if role == Role::Admin {
+            return Err(L2eError::AdminMustBeProposed);
+        }
+        if self.role_set_mut(role).insert(to) {
+            self.notify_on(Event::RoleGranted { role, to })
+                .expect("Notification Error");
+        }
+        Ok(())
+    }
+
+    pub fn revoke_role(&mut self, role: Role, from: ActorId) -> Result<(), L2eError> {
+        self.ensure_is_admin()?;
+        self.ensure_not_last_admin(role, from)?;
+        if self.role_set_mut(role).remove(&from) {
+            self.notify_on(Event::RoleRevoked { role, from })
+                .expect("Notification Error");
+        }
+        Ok(())
+    }
+
+    pub fn renounce_role(&mut self, role: Role) -> Result<(), L2eError> {
+        let from = msg::source();
+        self.ensure_not_last_admin(role, from)?;
+        if self.role_set_mut(role).remove(&from) {
+            self.notify_on(Event::RoleRevoked { role, from })
+                .expect("Notification Error");
+        }
+        Ok(())
+    }
+
+    pub fn propose_admin(&mut self, to: ActorId) -> Result<(), L2eError> {
+        self.ensure_is_admin()?;
+        self.get_mut().pending_admin = Some(to);
+        self.notify_on(Event::AdminProposed {
+            from: msg::source(),
+            to,
+        })
+        .expect("Notification Error");
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> Result<(), L2eError> {
+        let admin = msg::source();
+        if self.get().pending_admin != Some(admin) {
+            return Err(L2eError::NotPendingAdmin);
+        }
+        let storage = self.get_mut();
+        storage.pending_admin = None;
+        storage.admins.insert(admin);
+        self.notify_on(Event::AdminAccepted { admin })
+            .expect("Notification Error");
+        Ok(())
+    }
+
+    pub fn pending_admin(&self) -> Result<Option<ActorId>, L2eError> {
+        Ok(self.get().pending_admin)
+    }
+
+    pub fn pause(&mut self, operation: Operation) -> Result<(), L2eError> {
+        self.ensure_is_pauser()?;
+        if self.get_mut().paused.insert(operation) {
+            self.notify_on(Event::Paused { operation })
+                .expect("Notification Error");
+        }
+        Ok(())
+    }
+
+    pub fn unpause(&mut self, operation: Operation) -> Result<(), L2eError> {
+        self.ensure_is_pauser()?;
+        if self.get_mut().paused.remove(&operation) {
+            self.notify_on(Event::Unpaused { operation })
+                .expect("Notification Error");
+        }
+        Ok(())
+    }
+
+    pub fn is_paused(&self, operation: Operation) -> Result<bool, L2eError> {
+        Ok(self.get().paused.contains(&operation))
+    }
+
+    pub fn has_role(&self, role: Role, account: ActorId) -> Result<bool, L2eError> {
+        Ok(self.role_set(role).contains(&account))
+    }
+
+    pub fn role_members(&self, role: Role) -> Result<Vec<ActorId>, L2eError> {
+        Ok(self.role_set(role).clone().into_iter().collect())
+    }
+}
+
+impl L2eTop {
+    fn ensure_is_admin(&self) -> Result<(), L2eError> {
+        if !self.get().admins.contains(&msg::source()) {
+            return Err(L2eError::NotAdmin);
+        }
+        Ok(())
+    }
+
+    fn ensure_is_pauser(&self) -> Result<(), L2eError> {
+        if !self.get().pausers.contains(&msg::source()) {
+            return Err(L2eError::NotPauser);
+        }
+        Ok(())
+    }
+
+    fn ensure_not_paused(&self, operation: Operation) -> Result<(), L2eError> {
+        if self.get().paused.contains(&operation) {
+            return Err(L2eError::Paused);
+        }
+        Ok(())
+    }
+
+    // the contract can't be left without an admin
+    fn ensure_not_last_admin(&self, role: Role, account: ActorId) -> Result<(), L2eError> {
+        let admins = &self.get().admins;
+        if role == Role::Admin && admins.len() == 1 && admins.contains(&account) {
+            return Err(L2eError::LastAdmin);
+        }
+        Ok(())
+    }
+
+    fn role_set(&self, role: Role) -> &'static HashSet<ActorId> {
+        let storage = self.get();
+        match role {
+            Role::Admin => &storage.admins,
+            Role::Motivator => &storage.motivators,
+            Role::Verifier => &storage.verifiers,
+            Role::Pauser => &storage.pausers,
+        }
+    }
+
+    fn role_set_mut(&mut self, role: Role) -> &'static mut HashSet<ActorId> {
+        let storage = self.get_mut();
+        match role {
+            Role::Admin => &mut storage.admins,
+            Role::Motivator => &mut storage.motivators,
+            Role::Verifier => &mut storage.verifiers,
+            Role::Pauser => &mut storage.pausers,
+        }
+    }
+
+    // registered and enabled contract of the given kind
+    fn ensure_contract(&self, address: ActorId, kind: ContractKind) -> Result<(), L2eError> {
+        let contract = self
+            .get()
+            .contracts
+            .get(&address)
+            .filter(|contract| contract.kind == kind)
+            .ok_or(L2eError::UnknownContract)?;
+        if !contract.enabled {
+            return Err(L2eError::ContractDisabled);
+        }
+        Ok(())
+    }
+
+    fn contract_addresses(&self, kind: ContractKind) -> Vec<ActorId> {
+        self.get()
+            .contracts
+            .iter()
+            .filter(|(_, contract)| contract.kind == kind)
+            .map(|(address, _)| *address)
+            .collect()
     }
 
-    pub fn add_contract_address(
+    async fn register_contract(
         &mut self,
-        erc20_address: ActorId,
-        erc721_address: ActorId,
-    ) -> bool {
-        let current_caller = msg::source();
-        if !self.admin_address.contains(&current_caller) {
-            let _ = self.notify_on(Event::NoAuthorityAddContractAddress);
-            panic!("NoAuthorityAddContractAddress");
+        address: ActorId,
+        kind: ContractKind,
+    ) -> Result<(), L2eError> {
+        self.ensure_is_admin()?;
+
+        let already_exist = match kind {
+            ContractKind::Vft => L2eError::AlreadyExistTokenAddress,
+            ContractKind::Vnft => L2eError::AlreadyExistNFTAddress,
+        };
+        if self.get().contracts.contains_key(&address) {
+            return Err(already_exist);
         }
 
-        // add erc20 contract address
-        let erc20_address_vec = &mut self.erc20_address;
-        if erc20_address_vec.contains(&erc20_address) {
-            let _ = self.notify_on(Event::AlreadyExistTokenAddress);
-            panic!("AlreadyExistTokenAddress");
+        let contract = Self::probe_contract(address, kind).await?;
+        gstd::debug!("register_contract contract:{:?}", contract);
+
+        // address may be registered while waiting for replies, check again before storing
+        if self.get().contracts.contains_key(&address) {
+            return Err(already_exist);
         }
-        erc20_address_vec.push(erc20_address);
+        self.get_mut().contracts.insert(address, contract);
 
-        // add erc721 contract address
-        let erc721_address_vec = &mut self.erc721_address;
-        if erc721_address_vec.contains(&erc721_address) {
-            let _ = self.notify_on(Event::AlreadyExistNFTAddress);
-            panic!("AlreadyExistNFTAddress");
+        self.notify_on(Event::ContractRegistered { address, kind })
+            .expect("Notification Error");
+
+        Ok(())
+    }
+
+    // make sure the contract speaks the expected interface and read its metadata
+    async fn probe_contract(
+        address: ActorId,
+        kind: ContractKind,
+    ) -> Result<ContractInfo, L2eError> {
+        let (_name, symbol, decimals) = match kind {
+            ContractKind::Vft => (
+                Self::probe::<vft::io::Name>(address, vft::io::Name::encode_call()).await?,
+                Self::probe::<vft::io::Symbol>(address, vft::io::Symbol::encode_call()).await?,
+                Self::probe::<vft::io::Decimals>(address, vft::io::Decimals::encode_call()).await?,
+            ),
+            ContractKind::Vnft => (
+                Self::probe::<vnft::io::Name>(address, vnft::io::Name::encode_call()).await?,
+                Self::probe::<vnft::io::Symbol>(address, vnft::io::Symbol::encode_call()).await?,
+                0,
+            ),
+        };
+        gstd::debug!("probe_contract name:{:?}", _name);
+
+        Ok(ContractInfo {
+            kind,
+            symbol,
+            decimals,
+            enabled: true,
+        })
+    }
+
+    // cross contract call, a failed or undecodable reply means another interface
+    async fn probe<A: sails_rs::calls::ActionIo>(
+        address: ActorId,
+        call_payload: Vec<u8>,
+    ) -> Result<A::Reply, L2eError> {
+        let reply_bytes = gstd::msg::send_bytes_for_reply(address, call_payload, 0, 0)
+            .map_err(|_| L2eError::TransactionFailed)?
+            .await
+            .map_err(|_| L2eError::UnexpectedContractInterface)?;
+        A::decode_reply(&reply_bytes).map_err(|_| L2eError::UnexpectedContractInterface)
+    }
+
+    async fn try_approve_balances(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        erc20: ActorId,
+        vara_value: U256,
+        token_value: U256,
+    ) -> Result<(u128, U256), L2eError> {
+        self.ensure_not_paused(Operation::ApproveBalances)?;
+        // declared vara_value must be the value attached to the message
+        let current_value = u128::try_from(vara_value).map_err(|_| L2eError::VaraValueMismatch)?;
+        if current_value != msg::value() {
+            return Err(L2eError::VaraValueMismatch);
         }
-        erc721_address_vec.push(erc721_address);
+        gstd::debug!("current_value-{}", current_value);
+        gstd::debug!("token_value:{}", token_value);
+        self.ensure_contract(erc20, ContractKind::Vft)?;
+        let current_erc20 = erc20;
+
+        if token_value > U256::from(0) {
+            gstd::debug!("token_value>0");
+            // check motivator role
+            if current_erc20 == self.get().l2e_erc20 && !self.get().motivators.contains(&owner) {
+                return Err(L2eError::NoAuthToApproveL2EToken);
+            }
+
+            gstd::debug!("current_erc20:{:?}", current_erc20);
+
+            // cross contract call
+            let call_payload = vft::io::BalanceOf::encode_call(owner);
+            let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc20, call_payload, 0, 0)
+                .map_err(|_| L2eError::TransactionFailed)?
+                .await
+                .map_err(|_| L2eError::TransactionFailed)?;
+            let result_balance_of =
+                <vft::io::BalanceOf as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes);
+
+            // let result_balance_of = build_call::<DefaultEnvironment>()
+            //     // ERC20 address, gas_limit must be some value when in mainnet
+            //     .call(current_erc20)
+            //     .call_v1()
+            //     .gas_limit(0)
+            //     .transferred_value(0)
+            //     .exec_input(
+            //         ExecutionInput::new(Selector::new(ink::selector_bytes!("balanceOf")))
+            //             .push_arg(owner),
+            //     )
+            //     .returns::<Balance>()
+            //     .try_invoke()
+            //     .expect("Failed to get result_balance_of");
+            //     // .map_err(|e| format!("approve_balances failed: {:?}", e));
+
+            gstd::debug!("result_balance_of error:{:?}", result_balance_of);
+
+            if let Ok(balance_of) = result_balance_of {
+                if token_value > balance_of / 1000 {
+                    return Err(L2eError::InsufficientOwnerDepositTokens);
+                }
+            }
+
+            // cross contract call
+            // claims pull tokens from owner through this program, owner must approve it before.
+            let call_payload = vft::io::Allowance::encode_call(owner, exec::program_id());
+            let reply_bytes = gstd::msg::send_bytes_for_reply(current_erc20, call_payload, 0, 0)
+                .map_err(|_| L2eError::TransactionFailed)?
+                .await
+                .map_err(|_| L2eError::TransactionFailed)?;
+            let allowance =
+                <vft::io::Allowance as sails_rs::calls::ActionIo>::decode_reply(&reply_bytes)
+                    .map_err(|_| L2eError::TransactionFailed)?;
 
-        true
+            // let result_approve = build_call::<DefaultEnvironment>()
+            //     // ERC20 address, gas_limit must be some value when in mainnet
+            //     .call(current_erc20)
+            //     .call_v1()
+            //     .gas_limit(0)
+            //     .transferred_value(0)
+            //     .exec_input(
+            //         ExecutionInput::new(Selector::new(ink::selector_bytes!("approve")))
+            //             .push_arg(spender)
+            //             .push_arg(token_value),
+            //     )
+            //     .returns::<Result<(), Error>>()
+            //     .try_invoke()
+            //     .map_err(|e| format!("approve_balances failed: {:?}", e));
+
+            gstd::debug!("allowance:{:?}", allowance);
+
+            // allowance is shared by all spenders approved by owner in this erc20
+            let reserved = self
+                .get()
+                .approved_erc20
+                .iter()
+                .filter(|&(&(o, _), &erc20)| o == owner && erc20 == current_erc20)
+                .filter_map(|(&(_, s), _)| Some(self.approved_value(owner, s)?.1))
+                .fold(U256::zero(), |acc, v| acc.saturating_add(v));
+            if reserved.saturating_add(token_value) > allowance {
+                return Err(L2eError::InsufficientApproveTokens);
+            }
+        }
+        gstd::debug!("token_value  over");
+        if self.get().balances.contains_key(&spender) {
+            gstd::debug!("self.balances.contains(spender)");
+            let owner_value = self
+                .get_mut()
+                .balances
+                .get_mut(&spender)
+                .expect("failed to take owner value");
+            gstd::debug!("owner_value::{:?}", owner_value);
+            if owner_value.iter().any(|&(o, _, _)| o == owner) {
+                return Err(L2eError::BalancesAlreadyApproved);
+            }
+
+            owner_value.push((owner, current_value, token_value));
+            // self.balances.insert(spender, owner_value.clone());
+        } else {
+            let mut owner_value = Vec::new();
+            owner_value.push((owner, current_value, token_value));
+            gstd::debug!("owner_value--{:?}", owner_value);
+            self.get_mut().balances.insert(spender, owner_value);
+        }
+        if token_value > U256::from(0) {
+            self.get_mut()
+                .approved_erc20
+                .insert((owner, spender), current_erc20);
+        }
+        gstd::debug!("owner_value--over");
+
+        self.notify_on(Event::BalancesApproved {
+            owner,
+            spender,
+            vara: current_value,
+            tokens: token_value,
+            erc20: current_erc20,
+        })
+        .expect("Notification Error");
+
+        Ok((current_value, token_value))
+    }
+
+    fn approved_value(&self, owner: ActorId, spender: ActorId) -> Option<(u128, U256)> {
+        self.get()
+            .balances
+            .get(&spender)?
+            .iter()
+            .find(|&&(o, _, _)| o == owner)
+            .map(|&(_, vara_value, token_value)| (vara_value, token_value))
     }
 
-    pub fn add_auth_token_owner(&mut self, owner_address: ActorId) -> bool {
-        let current_caller = msg::source();
-        if !self.auth_token_owner.contains(&current_caller) {
-            let _ = self.notify_on(Event::NoAuthorityAddAuthTokenOwner);
-            panic!("NoAuthorityAddAuthTokenOwner");
+    // gives reserved value back to the approval, which may be removed while waiting for reply.
+    // vara of a removed approval is returned to owner.
+    fn restore_approval(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        vara_value: u128,
+        token_value: U256,
+    ) -> Result<(), L2eError> {
+        let Some((_, vara_v, token_v)) = self
+            .get_mut()
+            .balances
+            .get_mut(&spender)
+            .and_then(|vec| vec.iter_mut().find(|(o, _, _)| *o == owner))
+        else {
+            return self.refund_vara(owner, vara_value);
+        };
+        *vara_v = vara_v.saturating_add(vara_value);
+        *token_v = token_v.saturating_add(token_value);
+        Ok(())
+    }
+
+    // drops the approval, tokens can't be pulled for spender anymore
+    fn remove_approval(&mut self, owner: ActorId, spender: ActorId) {
+        self.get_mut().approved_erc20.remove(&(owner, spender));
+        let Some(owner_value) = self.get_mut().balances.get_mut(&spender) else {
+            return;
+        };
+        owner_value.retain(|&(o, _, _)| o != owner);
+        if owner_value.is_empty() {
+            self.get_mut().balances.remove(&spender);
         }
+    }
 
-        // add auth_token_owner address
-        let auth_owner_address_vec = &mut self.auth_token_owner;
-        if auth_owner_address_vec.contains(&owner_address) {
-            let _ = self.notify_on(Event::AlreadyExistAuthAddress);
-            panic!("AlreadyExistAuthAddress");
+    // undo stage claim of spender, unless the claimed count was moved on meanwhile
+    fn rollback_claim(&mut self, campaign_id: CampaignId, spender: ActorId, claimed_count: u32) {
+        let storage = self.get_mut();
+        storage.pending_claims.remove(&(campaign_id, spender));
+        if let Some(claimed) = storage.claimed_stages.get_mut(&(campaign_id, spender)) {
+            if *claimed == claimed_count + 1 {
+                *claimed = claimed_count;
+            }
         }
-        auth_owner_address_vec.push(owner_address);
+    }
 
-        true
+    fn add_unpaid_vara(&mut self, spender: ActorId, vara_value: u128) {
+        let unpaid = self.get_mut().unpaid_vara.entry(spender).or_default();
+        *unpaid = unpaid.saturating_add(vara_value);
+    }
+
+    fn refund_vara(&self, owner: ActorId, vara_value: u128) -> Result<(), L2eError> {
+        if vara_value == 0 {
+            return Ok(());
+        }
+        gstd::msg::send_bytes(owner, [], vara_value).map_err(|_| L2eError::TransactionFailed)?;
+        Ok(())
     }
 }
 
@@ -737,12 +1669,12 @@ pub struct L2eProgram;
 impl L2eProgram {
     // Program's constructor
     pub fn new(erc20: ActorId, erc721: ActorId) -> Self {
-        L2eTop::new(erc20, erc721);
+        L2eTop::seed(erc20, erc721);
         Self
     }
 
     // Exposed service
     pub fn l2e(&self) -> L2eTop {
-        L2eTop::default()
+        L2eTop::new()
     }
 }
@@ -8,8 +8,8 @@ pub struct Program(());
 
 #[program]
 impl Program {
-    pub fn new(name: String, symbol: String, decimals: u8) -> Self {
-        ExtendedService::seed(name, symbol, decimals);
+    pub fn new(name: String, symbol: String, decimals: u8, max_supply: Option<U256>) -> Self {
+        ExtendedService::seed(name, symbol, decimals, max_supply);
         Self(())
     }
 
@@ -4,23 +4,28 @@ use vft_service::{
     utils::{Error, Result, *},
 };
 
+#[derive(Debug)]
+pub enum MintError {
+    MaxSupplyExceeded,
+    Vft(Error),
+}
+
 pub fn mint(
     balances: &mut BalancesMap,
     total_supply: &mut U256,
+    max_supply: Option<U256>,
     to: ActorId,
     value: U256,
-) -> Result<bool> {
+) -> core::result::Result<bool, MintError> {
     if value.is_zero() {
         return Ok(false);
     }
 
-    let new_total_supply = total_supply
-        .checked_add(value)
-        .ok_or(Error::NumericOverflow)?;
+    let new_total_supply = checked_total_supply(*total_supply, max_supply, value)?;
 
     let new_to = funcs::balance_of(balances, to)
         .checked_add(value)
-        .ok_or(Error::NumericOverflow)?;
+        .ok_or(MintError::Vft(Error::NumericOverflow))?;
 
     balances.insert(to, new_to);
     *total_supply = new_total_supply;
@@ -28,6 +33,48 @@ pub fn mint(
     Ok(true)
 }
 
+// all-or-nothing: total supply and max supply are checked once for the whole batch
+pub fn batch_mint(
+    balances: &mut BalancesMap,
+    total_supply: &mut U256,
+    max_supply: Option<U256>,
+    recipients: &[(ActorId, U256)],
+) -> core::result::Result<bool, MintError> {
+    let value = recipients
+        .iter()
+        .try_fold(U256::zero(), |acc, (_, value)| acc.checked_add(*value))
+        .ok_or(MintError::Vft(Error::NumericOverflow))?;
+    if value.is_zero() {
+        return Ok(false);
+    }
+
+    let new_total_supply = checked_total_supply(*total_supply, max_supply, value)?;
+
+    // balances are bounded by total supply, they can't overflow here
+    for (to, value) in recipients.iter().filter(|(_, value)| !value.is_zero()) {
+        let new_to = funcs::balance_of(balances, *to) + *value;
+        balances.insert(*to, new_to);
+    }
+    *total_supply = new_total_supply;
+
+    Ok(true)
+}
+
+fn checked_total_supply(
+    total_supply: U256,
+    max_supply: Option<U256>,
+    value: U256,
+) -> core::result::Result<U256, MintError> {
+    let new_total_supply = total_supply
+        .checked_add(value)
+        .ok_or(MintError::Vft(Error::NumericOverflow))?;
+
+    if max_supply.is_some_and(|max_supply| new_total_supply > max_supply) {
+        return Err(MintError::MaxSupplyExceeded);
+    }
+    Ok(new_total_supply)
+}
+
 pub fn burn(
     balances: &mut BalancesMap,
     total_supply: &mut U256,
@@ -52,3 +99,39 @@ pub fn burn(
     *total_supply = new_total_supply;
     Ok(true)
 }
+
+// Snapshot checkpoints are (snapshot id, value) sorted by id, the value is recorded
+// lazily right before the first change after a snapshot is taken.
+pub fn update_snapshot(snapshots: &mut Vec<(u64, U256)>, current_id: u64, current_value: U256) {
+    if !matches!(snapshots.last(), Some((id, _)) if *id >= current_id) {
+        snapshots.push((current_id, current_value));
+    }
+}
+
+// Value at the snapshot, the current value is used when it hasn't changed since.
+pub fn value_at(snapshots: &[(u64, U256)], snapshot_id: u64, current_value: U256) -> U256 {
+    let index = snapshots.partition_point(|(id, _)| *id < snapshot_id);
+    snapshots
+        .get(index)
+        .map_or(current_value, |(_, value)| *value)
+}
+
+// Linear vesting, nothing is vested before the cliff and everything after the duration.
+pub fn vested_amount(
+    total: U256,
+    start_block: u32,
+    cliff: u32,
+    duration: u32,
+    block_height: u32,
+) -> U256 {
+    let elapsed = block_height.saturating_sub(start_block);
+    if elapsed < cliff {
+        return U256::zero();
+    }
+    if elapsed >= duration {
+        return total;
+    }
+    // total * elapsed / duration without overflowing U256
+    let (duration, elapsed) = (U256::from(duration), U256::from(elapsed));
+    total / duration * elapsed + total % duration * elapsed / duration
+}
@@ -1,22 +1,96 @@
-use gstd::msg;
-use sails_rs::{collections::HashSet, gstd::service, prelude::*};
+use gstd::{exec, msg};
+use sails_rs::{
+    collections::{HashMap, HashSet},
+    gstd::service,
+    prelude::*,
+};
 mod funcs;
 use crate::services;
-use vft_service::{Service as VftService, Storage};
+use vft_service::{funcs as vft_funcs, Service as VftService, Storage};
 
 #[derive(Default)]
 pub struct ExtendedStorage {
     minters: HashSet<ActorId>,
     burners: HashSet<ActorId>,
     admins: HashSet<ActorId>,
+    pending_admin: Option<ActorId>,
+    pausers: HashSet<ActorId>,
+    paused: bool,
+    max_supply: Option<U256>,
+    // owner -> nonce of the next permit
+    nonces: HashMap<ActorId, u64>,
+    // id of the last snapshot, 0 if none was taken
+    snapshot_id: u64,
+    account_snapshots: HashMap<ActorId, Vec<(u64, U256)>>,
+    total_supply_snapshots: Vec<(u64, U256)>,
+    // (creator, beneficiary) -> vesting schedule, locked tokens are held on the program balance
+    vestings: HashMap<(ActorId, ActorId), VestingInfo>,
 }
 
+// Tokens unlock linearly from start_block over duration blocks, nothing before the cliff.
+#[derive(Default, Debug, Encode, Decode, TypeInfo, Clone)]
+pub struct VestingInfo {
+    pub total: U256,
+    pub released: U256,
+    pub start_block: u32,
+    pub cliff: u32,
+    pub duration: u32,
+}
+
+// Signing context of substrate wallets, permits are signed with the owner's sr25519 key.
+const SIGNING_CONTEXT: &[u8] = b"substrate";
+
+// Max number of recipients of batch_mint and batch_transfer in one message,
+// keeps a batch within the gas limit of a block.
+pub const MAX_BATCH_SIZE: usize = 100;
+
 static mut EXTENDED_STORAGE: Option<ExtendedStorage> = None;
 
 #[derive(Encode, Decode, TypeInfo)]
 pub enum Event {
-    Minted { to: ActorId, value: U256 },
-    Burned { from: ActorId, value: U256 },
+    Minted {
+        to: ActorId,
+        value: U256,
+    },
+    Burned {
+        from: ActorId,
+        value: U256,
+    },
+    AdminProposed {
+        from: ActorId,
+        to: ActorId,
+    },
+    AdminAccepted {
+        admin: ActorId,
+    },
+    AdminRevoked {
+        admin: ActorId,
+    },
+    Paused {
+        by: ActorId,
+    },
+    Unpaused {
+        by: ActorId,
+    },
+    SnapshotTaken {
+        snapshot_id: u64,
+    },
+    Permitted {
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        nonce: u64,
+    },
+    VestingCreated {
+        from: ActorId,
+        beneficiary: ActorId,
+        total: U256,
+    },
+    VestingReleased {
+        from: ActorId,
+        beneficiary: ActorId,
+        value: U256,
+    },
 }
 #[derive(Clone)]
 pub struct ExtendedService {
@@ -24,13 +98,22 @@ pub struct ExtendedService {
 }
 
 impl ExtendedService {
-    pub fn seed(name: String, symbol: String, decimals: u8) -> Self {
+    pub fn seed(name: String, symbol: String, decimals: u8, max_supply: Option<U256>) -> Self {
         let admin = msg::source();
         unsafe {
             EXTENDED_STORAGE = Some(ExtendedStorage {
                 admins: [admin].into(),
                 minters: [admin].into(),
                 burners: [admin].into(),
+                pending_admin: None,
+                pausers: [admin].into(),
+                paused: false,
+                max_supply,
+                nonces: HashMap::new(),
+                snapshot_id: 0,
+                account_snapshots: HashMap::new(),
+                total_supply_snapshots: Vec::new(),
+                vestings: HashMap::new(),
             });
         };
         ExtendedService {
@@ -65,9 +148,19 @@ impl ExtendedService {
         if !self.get().minters.contains(&msg::source()) {
             panic!("Not allowed to mint")
         };
+        self.ensure_not_paused();
+        self.update_account_snapshot(to);
+        self.update_total_supply_snapshot();
 
+        let max_supply = self.get().max_supply;
         let mutated = services::utils::panicking(|| {
-            funcs::mint(Storage::balances(), Storage::total_supply(), to, value)
+            funcs::mint(
+                Storage::balances(),
+                Storage::total_supply(),
+                max_supply,
+                to,
+                value,
+            )
         });
         if mutated {
             self.notify_on(Event::Minted { to, value })
@@ -76,10 +169,46 @@ impl ExtendedService {
         mutated
     }
 
+    pub fn batch_mint(&mut self, recipients: Vec<(ActorId, U256)>) -> bool {
+        if !self.get().minters.contains(&msg::source()) {
+            panic!("Not allowed to mint")
+        };
+        self.ensure_not_paused();
+        Self::ensure_batch_size(&recipients);
+        for (to, _) in &recipients {
+            self.update_account_snapshot(*to);
+        }
+        self.update_total_supply_snapshot();
+
+        let max_supply = self.get().max_supply;
+        let mutated = services::utils::panicking(|| {
+            funcs::batch_mint(
+                Storage::balances(),
+                Storage::total_supply(),
+                max_supply,
+                &recipients,
+            )
+        });
+        if mutated {
+            for (to, value) in recipients.into_iter().filter(|(_, value)| !value.is_zero()) {
+                self.notify_on(Event::Minted { to, value })
+                    .expect("Notification Error");
+            }
+        }
+        mutated
+    }
+
     pub fn burn(&mut self, from: ActorId, value: U256) -> bool {
         if !self.get().burners.contains(&msg::source()) {
             panic!("Not allowed to burn")
         };
+        // program balance holds the locked tokens of vestings
+        if from == exec::program_id() {
+            panic!("Locked tokens can't be burned")
+        };
+        self.ensure_not_paused();
+        self.update_account_snapshot(from);
+        self.update_total_supply_snapshot();
 
         let mutated = services::utils::panicking(|| {
             funcs::burn(Storage::balances(), Storage::total_supply(), from, value)
@@ -91,10 +220,146 @@ impl ExtendedService {
         mutated
     }
 
-    pub fn grant_admin_role(&mut self, to: ActorId) {
+    pub fn propose_admin(&mut self, to: ActorId) {
         self.ensure_is_admin();
-        self.get_mut().admins.insert(to);
+        self.get_mut().pending_admin = Some(to);
+        self.notify_on(Event::AdminProposed {
+            from: msg::source(),
+            to,
+        })
+        .expect("Notification Error");
+    }
+    pub fn accept_admin(&mut self) {
+        let admin = msg::source();
+        if self.get().pending_admin != Some(admin) {
+            panic!("Not pending admin")
+        };
+        let storage = self.get_mut();
+        storage.pending_admin = None;
+        storage.admins.insert(admin);
+        self.notify_on(Event::AdminAccepted { admin })
+            .expect("Notification Error");
     }
+    // owner allowance signed off-chain, anyone can submit it.
+    // signed payload is `(program_id, owner, spender, value, deadline, nonce).encode()`.
+    pub fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        nonce: u64,
+        signature: Vec<u8>,
+    ) -> bool {
+        if exec::block_timestamp() > deadline {
+            panic!("Permit expired")
+        };
+        if self.nonces(owner) != nonce {
+            panic!("Invalid nonce")
+        };
+        let payload = (exec::program_id(), owner, spender, value, deadline, nonce).encode();
+        let verified = schnorrkel::PublicKey::from_bytes(owner.as_ref())
+            .and_then(|public| {
+                let signature = schnorrkel::Signature::from_bytes(&signature)?;
+                public.verify_simple(SIGNING_CONTEXT, &payload, &signature)
+            })
+            .is_ok();
+        if !verified {
+            panic!("Invalid signature")
+        };
+
+        self.get_mut().nonces.insert(owner, nonce + 1);
+        let mutated = vft_funcs::approve(Storage::allowances(), owner, spender, value);
+        self.notify_on(Event::Permitted {
+            owner,
+            spender,
+            value,
+            nonce,
+        })
+        .expect("Notification Error");
+        mutated
+    }
+
+    pub fn snapshot(&mut self) -> u64 {
+        self.ensure_is_admin();
+        let storage = self.get_mut();
+        storage.snapshot_id += 1;
+        let snapshot_id = storage.snapshot_id;
+        self.notify_on(Event::SnapshotTaken { snapshot_id })
+            .expect("Notification Error");
+        snapshot_id
+    }
+
+    // locks total tokens of the caller on the program until they vest to beneficiary.
+    // every creator has its own schedule for a beneficiary.
+    pub fn create_vesting(
+        &mut self,
+        beneficiary: ActorId,
+        total: U256,
+        start_block: u32,
+        cliff: u32,
+        duration: u32,
+    ) {
+        self.ensure_not_paused();
+        if total.is_zero() || duration == 0 || cliff > duration {
+            panic!("Invalid vesting schedule")
+        };
+        let from = msg::source();
+        if self.get().vestings.contains_key(&(from, beneficiary)) {
+            panic!("Vesting already exists")
+        };
+
+        self.transfer_tokens(from, exec::program_id(), total);
+        self.get_mut().vestings.insert(
+            (from, beneficiary),
+            VestingInfo {
+                total,
+                released: U256::zero(),
+                start_block,
+                cliff,
+                duration,
+            },
+        );
+        self.notify_on(Event::VestingCreated {
+            from,
+            beneficiary,
+            total,
+        })
+        .expect("Notification Error");
+    }
+
+    // transfers vested tokens to beneficiary, anyone can trigger it
+    pub fn release(&mut self, from: ActorId, beneficiary: ActorId) -> U256 {
+        self.ensure_not_paused();
+        let Some(vesting) = self.get_mut().vestings.get_mut(&(from, beneficiary)) else {
+            panic!("No vesting")
+        };
+        let vested = funcs::vested_amount(
+            vesting.total,
+            vesting.start_block,
+            vesting.cliff,
+            vesting.duration,
+            exec::block_height(),
+        );
+        let value = vested - vesting.released;
+        if value.is_zero() {
+            return value;
+        }
+        vesting.released = vested;
+        if vesting.released == vesting.total {
+            self.get_mut().vestings.remove(&(from, beneficiary));
+        }
+
+        self.transfer_tokens(exec::program_id(), beneficiary, value);
+        self.notify_on(Event::VestingReleased {
+            from,
+            beneficiary,
+            value,
+        })
+        .expect("Notification Error");
+        value
+    }
+
     pub fn grant_minter_role(&mut self, to: ActorId) {
         self.ensure_is_admin();
         self.get_mut().minters.insert(to);
@@ -103,10 +368,21 @@ impl ExtendedService {
         self.ensure_is_admin();
         self.get_mut().burners.insert(to);
     }
+    pub fn grant_pauser_role(&mut self, to: ActorId) {
+        self.ensure_is_admin();
+        self.get_mut().pausers.insert(to);
+    }
 
     pub fn revoke_admin_role(&mut self, from: ActorId) {
         self.ensure_is_admin();
-        self.get_mut().admins.remove(&from);
+        let admins = &mut self.get_mut().admins;
+        if admins.len() == 1 && admins.contains(&from) {
+            panic!("Last admin")
+        };
+        if admins.remove(&from) {
+            self.notify_on(Event::AdminRevoked { admin: from })
+                .expect("Notification Error");
+        }
     }
     pub fn revoke_minter_role(&mut self, from: ActorId) {
         self.ensure_is_admin();
@@ -116,6 +392,53 @@ impl ExtendedService {
         self.ensure_is_admin();
         self.get_mut().burners.remove(&from);
     }
+    pub fn revoke_pauser_role(&mut self, from: ActorId) {
+        self.ensure_is_admin();
+        self.get_mut().pausers.remove(&from);
+    }
+
+    pub fn pause(&mut self) {
+        self.ensure_is_pauser();
+        if !self.get().paused {
+            self.get_mut().paused = true;
+            self.notify_on(Event::Paused { by: msg::source() })
+                .expect("Notification Error");
+        }
+    }
+    pub fn unpause(&mut self) {
+        self.ensure_is_pauser();
+        if self.get().paused {
+            self.get_mut().paused = false;
+            self.notify_on(Event::Unpaused { by: msg::source() })
+                .expect("Notification Error");
+        }
+    }
+
+    // transfers of the base service are blocked while paused
+    pub fn transfer(&mut self, to: ActorId, value: U256) -> bool {
+        self.ensure_not_paused();
+        self.update_account_snapshot(msg::source());
+        self.update_account_snapshot(to);
+        self.vft.transfer(to, value)
+    }
+    pub fn transfer_from(&mut self, from: ActorId, to: ActorId, value: U256) -> bool {
+        self.ensure_not_paused();
+        self.update_account_snapshot(from);
+        self.update_account_snapshot(to);
+        self.vft.transfer_from(from, to, value)
+    }
+    // a failed transfer panics and reverts the whole batch
+    pub fn batch_transfer(&mut self, recipients: Vec<(ActorId, U256)>) -> bool {
+        self.ensure_not_paused();
+        Self::ensure_batch_size(&recipients);
+        self.update_account_snapshot(msg::source());
+        let mut mutated = false;
+        for (to, value) in recipients {
+            self.update_account_snapshot(to);
+            mutated |= self.vft.transfer(to, value);
+        }
+        mutated
+    }
     pub fn minters(&self) -> Vec<ActorId> {
         self.get().minters.clone().into_iter().collect()
     }
@@ -127,6 +450,54 @@ impl ExtendedService {
     pub fn admins(&self) -> Vec<ActorId> {
         self.get().admins.clone().into_iter().collect()
     }
+
+    pub fn pending_admin(&self) -> Option<ActorId> {
+        self.get().pending_admin
+    }
+
+    pub fn pausers(&self) -> Vec<ActorId> {
+        self.get().pausers.clone().into_iter().collect()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.get().paused
+    }
+
+    pub fn max_supply(&self) -> Option<U256> {
+        self.get().max_supply
+    }
+
+    pub fn max_batch_size(&self) -> u32 {
+        MAX_BATCH_SIZE as u32
+    }
+
+    pub fn balance_of_at(&self, account: ActorId, snapshot_id: u64) -> U256 {
+        self.ensure_snapshot_exists(snapshot_id);
+        let current_balance = vft_funcs::balance_of(Storage::balances(), account);
+        self.get()
+            .account_snapshots
+            .get(&account)
+            .map_or(current_balance, |snapshots| {
+                funcs::value_at(snapshots, snapshot_id, current_balance)
+            })
+    }
+
+    pub fn total_supply_at(&self, snapshot_id: u64) -> U256 {
+        self.ensure_snapshot_exists(snapshot_id);
+        funcs::value_at(
+            &self.get().total_supply_snapshots,
+            snapshot_id,
+            *Storage::total_supply(),
+        )
+    }
+
+    pub fn vesting_info(&self, from: ActorId, beneficiary: ActorId) -> Option<VestingInfo> {
+        self.get().vestings.get(&(from, beneficiary)).cloned()
+    }
+
+    pub fn nonces(&self, owner: ActorId) -> u64 {
+        self.get().nonces.get(&owner).copied().unwrap_or_default()
+    }
 }
 
 impl ExtendedService {
@@ -135,6 +506,62 @@ impl ExtendedService {
             panic!("Not admin")
         };
     }
+    fn ensure_is_pauser(&self) {
+        if !self.get().pausers.contains(&msg::source()) {
+            panic!("Not pauser")
+        };
+    }
+    fn ensure_batch_size(recipients: &[(ActorId, U256)]) {
+        if recipients.len() > MAX_BATCH_SIZE {
+            panic!("Batch too large")
+        };
+    }
+    // moves tokens through the base service, so that it emits the Transfer event
+    fn transfer_tokens(&mut self, from: ActorId, to: ActorId, value: U256) {
+        self.update_account_snapshot(from);
+        self.update_account_snapshot(to);
+        let spender = msg::source();
+        if from == spender {
+            self.vft.transfer(to, value);
+        } else {
+            // released tokens leave the program balance, caller may move exactly them
+            vft_funcs::approve(Storage::allowances(), from, spender, value);
+            self.vft.transfer_from(from, to, value);
+        }
+    }
+    fn ensure_snapshot_exists(&self, snapshot_id: u64) {
+        if snapshot_id == 0 || snapshot_id > self.get().snapshot_id {
+            panic!("Invalid snapshot id")
+        };
+    }
+    fn update_account_snapshot(&mut self, account: ActorId) {
+        if self.get().snapshot_id == 0 {
+            return;
+        }
+        let storage = self.get_mut();
+        let balance = vft_funcs::balance_of(Storage::balances(), account);
+        funcs::update_snapshot(
+            storage.account_snapshots.entry(account).or_default(),
+            storage.snapshot_id,
+            balance,
+        );
+    }
+    fn update_total_supply_snapshot(&mut self) {
+        if self.get().snapshot_id == 0 {
+            return;
+        }
+        let storage = self.get_mut();
+        funcs::update_snapshot(
+            &mut storage.total_supply_snapshots,
+            storage.snapshot_id,
+            *Storage::total_supply(),
+        );
+    }
+    fn ensure_not_paused(&self) {
+        if self.get().paused {
+            panic!("Paused")
+        };
+    }
 }
 impl AsRef<VftService> for ExtendedService {
     fn as_ref(&self) -> &VftService {
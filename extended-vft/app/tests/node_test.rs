@@ -69,9 +69,12 @@ async fn test_grant_role() -> Result<()> {
     let (message_id, program_id) = init(&api).await;
     assert!(listener.message_processed(message_id).await?.succeed());
 
-    // Grant Admin Role
+    // Propose Admin
     let john_actor_id = john_api.get_actor_id();
-    let message_id = send_request!(api: &api, program_id: program_id, service_name: "Vft", action: "GrantAdminRole", payload: (john_actor_id));
+    let message_id = send_request!(api: &api, program_id: program_id, service_name: "Vft", action: "ProposeAdmin", payload: (john_actor_id));
+    assert!(listener.message_processed(message_id).await?.succeed());
+    // Accept Admin
+    let message_id = send_request!(api: &john_api, program_id: program_id, service_name: "Vft", action: "AcceptAdmin", payload: ());
     assert!(listener.message_processed(message_id).await?.succeed());
     // Check State
     let admins: Vec<ActorId> = get_state!(api: &api, listener: listener, program_id: program_id, service_name: "Vft", action: "Admins", return_type: Vec<ActorId>, payload: ());
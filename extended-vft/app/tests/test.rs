@@ -1,9 +1,14 @@
 use extended_vft_wasm::{
     traits::{ExtendedVftFactory, Vft},
+    vft::events::VftEvents,
     ExtendedVftFactory as Factory, Vft as VftClient,
 };
+use futures::StreamExt;
 use sails_rs::calls::*;
+use sails_rs::events::*;
 use sails_rs::gtest::calls::*;
+use sails_rs::{ActorId, U256};
+use schnorrkel::{ExpansionMode, MiniSecretKey};
 
 #[tokio::test]
 async fn test_basic_function() {
@@ -15,7 +20,7 @@ async fn test_basic_function() {
 
     let extended_vft_factory = Factory::new(program_space.clone());
     let extended_vft_id = extended_vft_factory
-        .new("name".to_string(), "symbol".to_string(), 10)
+        .new("name".to_string(), "symbol".to_string(), 10, None)
         .send_recv(code_id, "123")
         .await
         .unwrap();
@@ -127,7 +132,7 @@ async fn test_grant_role() {
 
     let extended_vft_factory = Factory::new(program_space.clone());
     let extended_vft_id = extended_vft_factory
-        .new("name".to_string(), "symbol".to_string(), 10)
+        .new("name".to_string(), "symbol".to_string(), 10, None)
         .send_recv(code_id, "123")
         .await
         .unwrap();
@@ -190,12 +195,29 @@ async fn test_grant_role() {
         .unwrap();
     assert_eq!(balance, 0.into());
 
-    // grant admin role
+    // propose admin
     client
-        .grant_admin_role(101.into())
+        .propose_admin(101.into())
         .send_recv(extended_vft_id)
         .await
         .unwrap();
+    let pending = client.pending_admin().recv(extended_vft_id).await.unwrap();
+    assert_eq!(pending, Some(101.into()));
+    // only the proposed admin can accept
+    let res = client
+        .accept_admin()
+        .with_args(GTestArgs::new(102.into()))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    client
+        .accept_admin()
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    let pending = client.pending_admin().recv(extended_vft_id).await.unwrap();
+    assert_eq!(pending, None);
     let admins = client.admins().recv(extended_vft_id).await.unwrap();
     assert_eq!(admins, vec![100.into(), 101.into()]);
 
@@ -207,6 +229,12 @@ async fn test_grant_role() {
         .unwrap();
     let admins = client.admins().recv(extended_vft_id).await.unwrap();
     assert_eq!(admins, vec![100.into()]);
+    // the last admin can't be removed
+    let res = client
+        .revoke_admin_role(100.into())
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
     client
         .revoke_minter_role(101.into())
         .send_recv(extended_vft_id)
@@ -222,3 +250,514 @@ async fn test_grant_role() {
     let burners = client.burners().recv(extended_vft_id).await.unwrap();
     assert_eq!(burners, vec![100.into()]);
 }
+
+#[tokio::test]
+async fn test_capped_supply_and_pause() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let mut client = VftClient::new(program_space.clone());
+
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vft_wasm.opt.wasm");
+
+    let extended_vft_factory = Factory::new(program_space.clone());
+    let extended_vft_id = extended_vft_factory
+        .new(
+            "name".to_string(),
+            "symbol".to_string(),
+            10,
+            Some(1_000.into()),
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+    let max_supply = client.max_supply().recv(extended_vft_id).await.unwrap();
+    assert_eq!(max_supply, Some(1_000.into()));
+
+    // mint up to max supply
+    let res = client
+        .mint(100.into(), 1_000.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    let res = client
+        .mint(100.into(), 1.into())
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    let total_supply = client.total_supply().recv(extended_vft_id).await.unwrap();
+    assert_eq!(total_supply, 1_000.into());
+
+    // only pausers can pause
+    let res = client
+        .pause()
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    client.pause().send_recv(extended_vft_id).await.unwrap();
+    let paused = client.is_paused().recv(extended_vft_id).await.unwrap();
+    assert!(paused);
+
+    // transfers and burns are blocked while paused
+    let res = client
+        .transfer(101.into(), 100.into())
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    let res = client
+        .burn(100.into(), 100.into())
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+
+    client.unpause().send_recv(extended_vft_id).await.unwrap();
+    let res = client
+        .transfer(101.into(), 100.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    let balance = client
+        .balance_of(101.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 100.into());
+}
+
+#[tokio::test]
+async fn test_batch_mint_and_transfer() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let mut client = VftClient::new(program_space.clone());
+
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vft_wasm.opt.wasm");
+
+    let extended_vft_factory = Factory::new(program_space.clone());
+    let extended_vft_id = extended_vft_factory
+        .new(
+            "name".to_string(),
+            "symbol".to_string(),
+            10,
+            Some(1_000.into()),
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+    let max_batch_size = client.max_batch_size().recv(extended_vft_id).await.unwrap();
+
+    // batch mint
+    let res = client
+        .batch_mint(vec![(100.into(), 500.into()), (101.into(), 300.into())])
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    let balance = client
+        .balance_of(101.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 300.into());
+
+    // whole batch is rejected when it exceeds max supply
+    let res = client
+        .batch_mint(vec![(102.into(), 100.into()), (103.into(), 101.into())])
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    let balance = client
+        .balance_of(102.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 0.into());
+    let total_supply = client.total_supply().recv(extended_vft_id).await.unwrap();
+    assert_eq!(total_supply, 800.into());
+
+    // only minters can batch mint
+    let res = client
+        .batch_mint(vec![(101.into(), 1.into())])
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+
+    // batch transfer
+    let res = client
+        .batch_transfer(vec![(102.into(), 100.into()), (103.into(), 200.into())])
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    let balance = client
+        .balance_of(103.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 200.into());
+
+    // whole batch is rejected when sender balance runs out
+    let res = client
+        .batch_transfer(vec![(104.into(), 100.into()), (105.into(), 101.into())])
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    let balance = client
+        .balance_of(104.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 0.into());
+    let balance = client
+        .balance_of(100.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 200.into());
+
+    // batch size is limited
+    let recipients: Vec<_> = (0..max_batch_size as u64)
+        .map(|i| ((1_000 + i).into(), 1.into()))
+        .collect();
+    let res = client
+        .batch_mint(recipients.clone())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    // empty mints are a no-op, so only the size makes this one fail
+    let too_many: Vec<_> = (0..=max_batch_size as u64)
+        .map(|i| ((2_000 + i).into(), U256::zero()))
+        .collect();
+    let res = client.batch_mint(too_many).send_recv(extended_vft_id).await;
+    assert!(res.is_err());
+
+    let res = client
+        .batch_transfer(recipients.clone())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    let mut recipients = recipients;
+    recipients.push((2_000.into(), 1.into()));
+    let res = client
+        .batch_transfer(recipients)
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_permit() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let mut client = VftClient::new(program_space.clone());
+
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vft_wasm.opt.wasm");
+
+    let extended_vft_factory = Factory::new(program_space.clone());
+    let extended_vft_id = extended_vft_factory
+        .new("name".to_string(), "symbol".to_string(), 10, None)
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    let keypair = MiniSecretKey::from_bytes(&[7; 32])
+        .unwrap()
+        .expand_to_keypair(ExpansionMode::Ed25519);
+    let owner = ActorId::from(keypair.public.to_bytes());
+    let spender: ActorId = 101.into();
+    let value: U256 = 500.into();
+    let sign = |deadline: u64, nonce: u64| {
+        let payload =
+            vft_client::permit_payload(extended_vft_id, owner, spender, value, deadline, nonce);
+        keypair
+            .sign_simple(b"substrate", &payload)
+            .to_bytes()
+            .to_vec()
+    };
+
+    // expired permit
+    let res = client
+        .permit(owner, spender, value, 0, 0, sign(0, 0))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+
+    // signed by another account
+    let res = client
+        .permit(102.into(), spender, value, u64::MAX, 0, sign(u64::MAX, 0))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+
+    // anyone can submit a valid permit
+    let res = client
+        .permit(owner, spender, value, u64::MAX, 0, sign(u64::MAX, 0))
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(res);
+    let allowance = client
+        .allowance(owner, spender)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, value);
+    let nonce = client.nonces(owner).recv(extended_vft_id).await.unwrap();
+    assert_eq!(nonce, 1);
+
+    // permit can't be replayed
+    let res = client
+        .permit(owner, spender, value, u64::MAX, 0, sign(u64::MAX, 0))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_snapshots() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let mut client = VftClient::new(program_space.clone());
+
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vft_wasm.opt.wasm");
+
+    let extended_vft_factory = Factory::new(program_space.clone());
+    let extended_vft_id = extended_vft_factory
+        .new("name".to_string(), "symbol".to_string(), 10, None)
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    client
+        .mint(100.into(), 1_000.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+
+    // only admins can take snapshots
+    let res = client
+        .snapshot()
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    let first = client.snapshot().send_recv(extended_vft_id).await.unwrap();
+    assert_eq!(first, 1);
+
+    client
+        .transfer(101.into(), 300.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    client
+        .burn(100.into(), 200.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    let second = client.snapshot().send_recv(extended_vft_id).await.unwrap();
+    assert_eq!(second, 2);
+    client
+        .mint(101.into(), 50.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+
+    // balances at the first snapshot
+    let balance = client
+        .balance_of_at(100.into(), first)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 1_000.into());
+    let balance = client
+        .balance_of_at(101.into(), first)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 0.into());
+    let total_supply = client
+        .total_supply_at(first)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(total_supply, 1_000.into());
+
+    // balances at the second snapshot
+    let balance = client
+        .balance_of_at(100.into(), second)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 500.into());
+    let balance = client
+        .balance_of_at(101.into(), second)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 300.into());
+    let total_supply = client
+        .total_supply_at(second)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(total_supply, 800.into());
+
+    // snapshot that doesn't exist yet
+    let res = client.total_supply_at(3).recv(extended_vft_id).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_vesting() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let mut client = VftClient::new(program_space.clone());
+
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vft_wasm.opt.wasm");
+
+    let extended_vft_factory = Factory::new(program_space.clone());
+    let extended_vft_id = extended_vft_factory
+        .new("name".to_string(), "symbol".to_string(), 10, None)
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    client
+        .mint(100.into(), 1_000.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+
+    let mut listener = extended_vft_wasm::vft::events::listener(program_space.clone());
+    let mut events = listener.listen().await.unwrap();
+
+    // tokens are locked on the program
+    let start_block = program_space.system().block_height();
+    client
+        .create_vesting(101.into(), 1_000.into(), start_block, 10, 100)
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    let event = events.next().await.unwrap();
+    assert_eq!(
+        event,
+        (
+            extended_vft_id,
+            VftEvents::Transfer {
+                from: 100.into(),
+                to: extended_vft_id,
+                value: 1_000.into()
+            }
+        )
+    );
+    let balance = client
+        .balance_of(extended_vft_id)
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 1_000.into());
+    let res = client
+        .create_vesting(101.into(), 1_000.into(), start_block, 10, 100)
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+
+    // locked tokens can't be burned
+    let res = client
+        .burn(extended_vft_id, 1.into())
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+
+    // schedule of another creator doesn't block the first one
+    client
+        .mint(102.into(), 10.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    client
+        .create_vesting(101.into(), 10.into(), start_block, 10, 100)
+        .with_args(GTestArgs::new(102.into()))
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    let info = client
+        .vesting_info(102.into(), 101.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(info.total, 10.into());
+
+    // nothing is released before the cliff
+    let released = client
+        .release(100.into(), 101.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(released, 0.into());
+
+    // vested part is released linearly
+    program_space.system().run_to_block(start_block + 50);
+    let mut listener = extended_vft_wasm::vft::events::listener(program_space.clone());
+    let mut events = listener.listen().await.unwrap();
+    let released = client
+        .release(100.into(), 101.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(released >= 500.into() && released < 600.into());
+    let event = events.next().await.unwrap();
+    assert_eq!(
+        event,
+        (
+            extended_vft_id,
+            VftEvents::Transfer {
+                from: extended_vft_id,
+                to: 101.into(),
+                value: released
+            }
+        )
+    );
+    let info = client
+        .vesting_info(100.into(), 101.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(info.released, released);
+
+    // everything is released after the duration
+    program_space.system().run_to_block(start_block + 100);
+    client
+        .release(100.into(), 101.into())
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    let balance = client
+        .balance_of(101.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 1_000.into());
+    let info = client
+        .vesting_info(100.into(), 101.into())
+        .recv(extended_vft_id)
+        .await
+        .unwrap();
+    assert!(info.is_none());
+}
@@ -1,6 +1,6 @@
 use gclient::GearApi;
 use gear_core::ids::{MessageId, ProgramId};
-use sails_rs::{ActorId, Encode};
+use sails_rs::{ActorId, Encode, U256};
 
 pub const USERS_STR: &[&str] = &["//John", "//Mike", "//Dan"];
 
@@ -48,7 +48,12 @@ pub async fn get_new_client(api: &GearApi, name: &str) -> GearApi {
 }
 
 pub async fn init(api: &GearApi) -> (MessageId, ProgramId) {
-    let constructor = ("Name".to_string(), "Symbol".to_string(), 10_u8);
+    let constructor = (
+        "Name".to_string(),
+        "Symbol".to_string(),
+        10_u8,
+        None::<U256>,
+    );
     let request = ["New".encode(), constructor.encode()].concat();
 
     let path = "../target/wasm32-unknown-unknown/release/extended_vft_wasm.opt.wasm";
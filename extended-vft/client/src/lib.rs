@@ -2,3 +2,16 @@
 
 // Incorporate code generated based on the IDL file
 include!("vft_client.rs");
+
+/// Payload the owner signs with the sr25519 key of the account to authorize `Vft::permit`.
+/// `program_id` is the token program, so a signature can't be replayed on another token.
+pub fn permit_payload(
+    program_id: ActorId,
+    owner: ActorId,
+    spender: ActorId,
+    value: U256,
+    deadline: u64,
+    nonce: u64,
+) -> Vec<u8> {
+    (program_id, owner, spender, value, deadline, nonce).encode()
+}
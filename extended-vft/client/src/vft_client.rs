@@ -19,10 +19,16 @@ impl<R> VftFactory<R> {
 }
 impl<R: Remoting + Clone> traits::VftFactory for VftFactory<R> {
     type Args = R::Args;
-    fn new(&self, name: String, symbol: String, decimals: u8) -> impl Activation<Args = R::Args> {
+    fn new(
+        &self,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        max_supply: Option<U256>,
+    ) -> impl Activation<Args = R::Args> {
         RemotingAction::<_, vft_factory::io::New>::new(
             self.remoting.clone(),
-            (name, symbol, decimals),
+            (name, symbol, decimals, max_supply),
         )
     }
 }
@@ -34,13 +40,18 @@ pub mod vft_factory {
         pub struct New(());
         impl New {
             #[allow(dead_code)]
-            pub fn encode_call(name: String, symbol: String, decimals: u8) -> Vec<u8> {
-                <New as ActionIo>::encode_call(&(name, symbol, decimals))
+            pub fn encode_call(
+                name: String,
+                symbol: String,
+                decimals: u8,
+                max_supply: Option<U256>,
+            ) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&(name, symbol, decimals, max_supply))
             }
         }
         impl ActionIo for New {
             const ROUTE: &'static [u8] = &[12, 78, 101, 119];
-            type Params = (String, String, u8);
+            type Params = (String, String, u8, Option<U256>);
             type Reply = ();
         }
     }
@@ -55,11 +66,36 @@ impl<R> Vft<R> {
 }
 impl<R: Remoting + Clone> traits::Vft for Vft<R> {
     type Args = R::Args;
+    fn accept_admin(&mut self) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::AcceptAdmin>::new(self.remoting.clone(), ())
+    }
+    fn batch_mint(
+        &mut self,
+        recipients: Vec<(ActorId, U256)>,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vft::io::BatchMint>::new(self.remoting.clone(), recipients)
+    }
+    fn batch_transfer(
+        &mut self,
+        recipients: Vec<(ActorId, U256)>,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vft::io::BatchTransfer>::new(self.remoting.clone(), recipients)
+    }
     fn burn(&mut self, from: ActorId, value: U256) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::Burn>::new(self.remoting.clone(), (from, value))
     }
-    fn grant_admin_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
-        RemotingAction::<_, vft::io::GrantAdminRole>::new(self.remoting.clone(), to)
+    fn create_vesting(
+        &mut self,
+        beneficiary: ActorId,
+        total: U256,
+        start_block: u32,
+        cliff: u32,
+        duration: u32,
+    ) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::CreateVesting>::new(
+            self.remoting.clone(),
+            (beneficiary, total, start_block, cliff, duration),
+        )
     }
     fn grant_burner_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vft::io::GrantBurnerRole>::new(self.remoting.clone(), to)
@@ -67,9 +103,39 @@ impl<R: Remoting + Clone> traits::Vft for Vft<R> {
     fn grant_minter_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vft::io::GrantMinterRole>::new(self.remoting.clone(), to)
     }
+    fn grant_pauser_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::GrantPauserRole>::new(self.remoting.clone(), to)
+    }
     fn mint(&mut self, to: ActorId, value: U256) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::Mint>::new(self.remoting.clone(), (to, value))
     }
+    fn pause(&mut self) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::Pause>::new(self.remoting.clone(), ())
+    }
+    fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        nonce: u64,
+        signature: Vec<u8>,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vft::io::Permit>::new(
+            self.remoting.clone(),
+            (owner, spender, value, deadline, nonce, signature),
+        )
+    }
+    fn propose_admin(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::ProposeAdmin>::new(self.remoting.clone(), to)
+    }
+    fn release(
+        &mut self,
+        from: ActorId,
+        beneficiary: ActorId,
+    ) -> impl Call<Output = U256, Args = R::Args> {
+        RemotingAction::<_, vft::io::Release>::new(self.remoting.clone(), (from, beneficiary))
+    }
     fn revoke_admin_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vft::io::RevokeAdminRole>::new(self.remoting.clone(), from)
     }
@@ -79,12 +145,11 @@ impl<R: Remoting + Clone> traits::Vft for Vft<R> {
     fn revoke_minter_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vft::io::RevokeMinterRole>::new(self.remoting.clone(), from)
     }
-    fn approve(
-        &mut self,
-        spender: ActorId,
-        value: U256,
-    ) -> impl Call<Output = bool, Args = R::Args> {
-        RemotingAction::<_, vft::io::Approve>::new(self.remoting.clone(), (spender, value))
+    fn revoke_pauser_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::RevokePauserRole>::new(self.remoting.clone(), from)
+    }
+    fn snapshot(&mut self) -> impl Call<Output = u64, Args = R::Args> {
+        RemotingAction::<_, vft::io::Snapshot>::new(self.remoting.clone(), ())
     }
     fn transfer(&mut self, to: ActorId, value: U256) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::Transfer>::new(self.remoting.clone(), (to, value))
@@ -97,15 +162,63 @@ impl<R: Remoting + Clone> traits::Vft for Vft<R> {
     ) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::TransferFrom>::new(self.remoting.clone(), (from, to, value))
     }
+    fn unpause(&mut self) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vft::io::Unpause>::new(self.remoting.clone(), ())
+    }
+    fn approve(
+        &mut self,
+        spender: ActorId,
+        value: U256,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vft::io::Approve>::new(self.remoting.clone(), (spender, value))
+    }
     fn admins(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, vft::io::Admins>::new(self.remoting.clone(), ())
     }
+    fn balance_of_at(
+        &self,
+        account: ActorId,
+        snapshot_id: u64,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, vft::io::BalanceOfAt>::new(
+            self.remoting.clone(),
+            (account, snapshot_id),
+        )
+    }
     fn burners(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, vft::io::Burners>::new(self.remoting.clone(), ())
     }
+    fn is_paused(&self) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vft::io::IsPaused>::new(self.remoting.clone(), ())
+    }
+    fn max_batch_size(&self) -> impl Query<Output = u32, Args = R::Args> {
+        RemotingAction::<_, vft::io::MaxBatchSize>::new(self.remoting.clone(), ())
+    }
+    fn max_supply(&self) -> impl Query<Output = Option<U256>, Args = R::Args> {
+        RemotingAction::<_, vft::io::MaxSupply>::new(self.remoting.clone(), ())
+    }
     fn minters(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, vft::io::Minters>::new(self.remoting.clone(), ())
     }
+    fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, vft::io::Nonces>::new(self.remoting.clone(), owner)
+    }
+    fn pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, vft::io::PendingAdmin>::new(self.remoting.clone(), ())
+    }
+    fn pausers(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, vft::io::Pausers>::new(self.remoting.clone(), ())
+    }
+    fn total_supply_at(&self, snapshot_id: u64) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, vft::io::TotalSupplyAt>::new(self.remoting.clone(), snapshot_id)
+    }
+    fn vesting_info(
+        &self,
+        from: ActorId,
+        beneficiary: ActorId,
+    ) -> impl Query<Output = Option<VestingInfo>, Args = R::Args> {
+        RemotingAction::<_, vft::io::VestingInfo>::new(self.remoting.clone(), (from, beneficiary))
+    }
     fn allowance(
         &self,
         owner: ActorId,
@@ -134,6 +247,48 @@ pub mod vft {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AcceptAdmin(());
+        impl AcceptAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptAdmin {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 44, 65, 99, 99, 101, 112, 116, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = ();
+        }
+        pub struct BatchMint(());
+        impl BatchMint {
+            #[allow(dead_code)]
+            pub fn encode_call(recipients: Vec<(ActorId, U256)>) -> Vec<u8> {
+                <BatchMint as ActionIo>::encode_call(&recipients)
+            }
+        }
+        impl ActionIo for BatchMint {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 36, 66, 97, 116, 99, 104, 77, 105, 110, 116,
+            ];
+            type Params = Vec<(ActorId, U256)>;
+            type Reply = bool;
+        }
+        pub struct BatchTransfer(());
+        impl BatchTransfer {
+            #[allow(dead_code)]
+            pub fn encode_call(recipients: Vec<(ActorId, U256)>) -> Vec<u8> {
+                <BatchTransfer as ActionIo>::encode_call(&recipients)
+            }
+        }
+        impl ActionIo for BatchTransfer {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 52, 66, 97, 116, 99, 104, 84, 114, 97, 110, 115, 102, 101, 114,
+            ];
+            type Params = Vec<(ActorId, U256)>;
+            type Reply = bool;
+        }
         pub struct Burn(());
         impl Burn {
             #[allow(dead_code)]
@@ -146,19 +301,30 @@ pub mod vft {
             type Params = (ActorId, U256);
             type Reply = bool;
         }
-        pub struct GrantAdminRole(());
-        impl GrantAdminRole {
+        pub struct CreateVesting(());
+        impl CreateVesting {
             #[allow(dead_code)]
-            pub fn encode_call(to: ActorId) -> Vec<u8> {
-                <GrantAdminRole as ActionIo>::encode_call(&to)
+            pub fn encode_call(
+                beneficiary: ActorId,
+                total: U256,
+                start_block: u32,
+                cliff: u32,
+                duration: u32,
+            ) -> Vec<u8> {
+                <CreateVesting as ActionIo>::encode_call(&(
+                    beneficiary,
+                    total,
+                    start_block,
+                    cliff,
+                    duration,
+                ))
             }
         }
-        impl ActionIo for GrantAdminRole {
+        impl ActionIo for CreateVesting {
             const ROUTE: &'static [u8] = &[
-                12, 86, 102, 116, 56, 71, 114, 97, 110, 116, 65, 100, 109, 105, 110, 82, 111, 108,
-                101,
+                12, 86, 102, 116, 52, 67, 114, 101, 97, 116, 101, 86, 101, 115, 116, 105, 110, 103,
             ];
-            type Params = ActorId;
+            type Params = (ActorId, U256, u32, u32, u32);
             type Reply = ();
         }
         pub struct GrantBurnerRole(());
@@ -191,6 +357,21 @@ pub mod vft {
             type Params = ActorId;
             type Reply = ();
         }
+        pub struct GrantPauserRole(());
+        impl GrantPauserRole {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <GrantPauserRole as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for GrantPauserRole {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 60, 71, 114, 97, 110, 116, 80, 97, 117, 115, 101, 114, 82, 111,
+                108, 101,
+            ];
+            type Params = ActorId;
+            type Reply = ();
+        }
         pub struct Mint(());
         impl Mint {
             #[allow(dead_code)]
@@ -203,6 +384,65 @@ pub mod vft {
             type Params = (ActorId, U256);
             type Reply = bool;
         }
+        pub struct Pause(());
+        impl Pause {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Pause as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Pause {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 20, 80, 97, 117, 115, 101];
+            type Params = ();
+            type Reply = ();
+        }
+        pub struct Permit(());
+        impl Permit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+                deadline: u64,
+                nonce: u64,
+                signature: Vec<u8>,
+            ) -> Vec<u8> {
+                <Permit as ActionIo>::encode_call(&(
+                    owner, spender, value, deadline, nonce, signature,
+                ))
+            }
+        }
+        impl ActionIo for Permit {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 24, 80, 101, 114, 109, 105, 116];
+            type Params = (ActorId, ActorId, U256, u64, u64, Vec<u8>);
+            type Reply = bool;
+        }
+        pub struct ProposeAdmin(());
+        impl ProposeAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <ProposeAdmin as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for ProposeAdmin {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 48, 80, 114, 111, 112, 111, 115, 101, 65, 100, 109, 105, 110,
+            ];
+            type Params = ActorId;
+            type Reply = ();
+        }
+        pub struct Release(());
+        impl Release {
+            #[allow(dead_code)]
+            pub fn encode_call(from: ActorId, beneficiary: ActorId) -> Vec<u8> {
+                <Release as ActionIo>::encode_call(&(from, beneficiary))
+            }
+        }
+        impl ActionIo for Release {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 28, 82, 101, 108, 101, 97, 115, 101];
+            type Params = (ActorId, ActorId);
+            type Reply = U256;
+        }
         pub struct RevokeAdminRole(());
         impl RevokeAdminRole {
             #[allow(dead_code)]
@@ -248,17 +488,33 @@ pub mod vft {
             type Params = ActorId;
             type Reply = ();
         }
-        pub struct Approve(());
-        impl Approve {
+        pub struct RevokePauserRole(());
+        impl RevokePauserRole {
             #[allow(dead_code)]
-            pub fn encode_call(spender: ActorId, value: U256) -> Vec<u8> {
-                <Approve as ActionIo>::encode_call(&(spender, value))
+            pub fn encode_call(from: ActorId) -> Vec<u8> {
+                <RevokePauserRole as ActionIo>::encode_call(&from)
             }
         }
-        impl ActionIo for Approve {
-            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 28, 65, 112, 112, 114, 111, 118, 101];
-            type Params = (ActorId, U256);
-            type Reply = bool;
+        impl ActionIo for RevokePauserRole {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 64, 82, 101, 118, 111, 107, 101, 80, 97, 117, 115, 101, 114, 82,
+                111, 108, 101,
+            ];
+            type Params = ActorId;
+            type Reply = ();
+        }
+        pub struct Snapshot(());
+        impl Snapshot {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Snapshot as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Snapshot {
+            const ROUTE: &'static [u8] =
+                &[12, 86, 102, 116, 32, 83, 110, 97, 112, 115, 104, 111, 116];
+            type Params = ();
+            type Reply = u64;
         }
         pub struct Transfer(());
         impl Transfer {
@@ -287,6 +543,30 @@ pub mod vft {
             type Params = (ActorId, ActorId, U256);
             type Reply = bool;
         }
+        pub struct Unpause(());
+        impl Unpause {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Unpause as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Unpause {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 28, 85, 110, 112, 97, 117, 115, 101];
+            type Params = ();
+            type Reply = ();
+        }
+        pub struct Approve(());
+        impl Approve {
+            #[allow(dead_code)]
+            pub fn encode_call(spender: ActorId, value: U256) -> Vec<u8> {
+                <Approve as ActionIo>::encode_call(&(spender, value))
+            }
+        }
+        impl ActionIo for Approve {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 28, 65, 112, 112, 114, 111, 118, 101];
+            type Params = (ActorId, U256);
+            type Reply = bool;
+        }
         pub struct Admins(());
         impl Admins {
             #[allow(dead_code)]
@@ -299,6 +579,20 @@ pub mod vft {
             type Params = ();
             type Reply = Vec<ActorId>;
         }
+        pub struct BalanceOfAt(());
+        impl BalanceOfAt {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, snapshot_id: u64) -> Vec<u8> {
+                <BalanceOfAt as ActionIo>::encode_call(&(account, snapshot_id))
+            }
+        }
+        impl ActionIo for BalanceOfAt {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 44, 66, 97, 108, 97, 110, 99, 101, 79, 102, 65, 116,
+            ];
+            type Params = (ActorId, u64);
+            type Reply = U256;
+        }
         pub struct Burners(());
         impl Burners {
             #[allow(dead_code)]
@@ -311,6 +605,47 @@ pub mod vft {
             type Params = ();
             type Reply = Vec<ActorId>;
         }
+        pub struct IsPaused(());
+        impl IsPaused {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <IsPaused as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for IsPaused {
+            const ROUTE: &'static [u8] =
+                &[12, 86, 102, 116, 32, 73, 115, 80, 97, 117, 115, 101, 100];
+            type Params = ();
+            type Reply = bool;
+        }
+        pub struct MaxBatchSize(());
+        impl MaxBatchSize {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <MaxBatchSize as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for MaxBatchSize {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 48, 77, 97, 120, 66, 97, 116, 99, 104, 83, 105, 122, 101,
+            ];
+            type Params = ();
+            type Reply = u32;
+        }
+        pub struct MaxSupply(());
+        impl MaxSupply {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <MaxSupply as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for MaxSupply {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 36, 77, 97, 120, 83, 117, 112, 112, 108, 121,
+            ];
+            type Params = ();
+            type Reply = Option<U256>;
+        }
         pub struct Minters(());
         impl Minters {
             #[allow(dead_code)]
@@ -323,6 +658,72 @@ pub mod vft {
             type Params = ();
             type Reply = Vec<ActorId>;
         }
+        pub struct Nonces(());
+        impl Nonces {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <Nonces as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for Nonces {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 24, 78, 111, 110, 99, 101, 115];
+            type Params = ActorId;
+            type Reply = u64;
+        }
+        pub struct PendingAdmin(());
+        impl PendingAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <PendingAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for PendingAdmin {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 48, 80, 101, 110, 100, 105, 110, 103, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
+        pub struct Pausers(());
+        impl Pausers {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Pausers as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Pausers {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 28, 80, 97, 117, 115, 101, 114, 115];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct TotalSupplyAt(());
+        impl TotalSupplyAt {
+            #[allow(dead_code)]
+            pub fn encode_call(snapshot_id: u64) -> Vec<u8> {
+                <TotalSupplyAt as ActionIo>::encode_call(&snapshot_id)
+            }
+        }
+        impl ActionIo for TotalSupplyAt {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 52, 84, 111, 116, 97, 108, 83, 117, 112, 112, 108, 121, 65, 116,
+            ];
+            type Params = u64;
+            type Reply = U256;
+        }
+        pub struct VestingInfo(());
+        impl VestingInfo {
+            #[allow(dead_code)]
+            pub fn encode_call(from: ActorId, beneficiary: ActorId) -> Vec<u8> {
+                <VestingInfo as ActionIo>::encode_call(&(from, beneficiary))
+            }
+        }
+        impl ActionIo for VestingInfo {
+            const ROUTE: &'static [u8] = &[
+                12, 86, 102, 116, 44, 86, 101, 115, 116, 105, 110, 103, 73, 110, 102, 111,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Option<super::VestingInfo>;
+        }
         pub struct Allowance(());
         impl Allowance {
             #[allow(dead_code)]
@@ -418,6 +819,41 @@ pub mod vft {
                 from: ActorId,
                 value: U256,
             },
+            AdminProposed {
+                from: ActorId,
+                to: ActorId,
+            },
+            AdminAccepted {
+                admin: ActorId,
+            },
+            AdminRevoked {
+                admin: ActorId,
+            },
+            Paused {
+                by: ActorId,
+            },
+            Unpaused {
+                by: ActorId,
+            },
+            SnapshotTaken {
+                snapshot_id: u64,
+            },
+            Permitted {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+                nonce: u64,
+            },
+            VestingCreated {
+                from: ActorId,
+                beneficiary: ActorId,
+                total: U256,
+            },
+            VestingReleased {
+                from: ActorId,
+                beneficiary: ActorId,
+                value: U256,
+            },
             Approval {
                 owner: ActorId,
                 spender: ActorId,
@@ -434,6 +870,25 @@ pub mod vft {
             const EVENT_NAMES: &'static [&'static [u8]] = &[
                 &[24, 77, 105, 110, 116, 101, 100],
                 &[24, 66, 117, 114, 110, 101, 100],
+                &[
+                    52, 65, 100, 109, 105, 110, 80, 114, 111, 112, 111, 115, 101, 100,
+                ],
+                &[
+                    52, 65, 100, 109, 105, 110, 65, 99, 99, 101, 112, 116, 101, 100,
+                ],
+                &[48, 65, 100, 109, 105, 110, 82, 101, 118, 111, 107, 101, 100],
+                &[24, 80, 97, 117, 115, 101, 100],
+                &[32, 85, 110, 112, 97, 117, 115, 101, 100],
+                &[
+                    52, 83, 110, 97, 112, 115, 104, 111, 116, 84, 97, 107, 101, 110,
+                ],
+                &[36, 80, 101, 114, 109, 105, 116, 116, 101, 100],
+                &[
+                    56, 86, 101, 115, 116, 105, 110, 103, 67, 114, 101, 97, 116, 101, 100,
+                ],
+                &[
+                    60, 86, 101, 115, 116, 105, 110, 103, 82, 101, 108, 101, 97, 115, 101, 100,
+                ],
                 &[32, 65, 112, 112, 114, 111, 118, 97, 108],
                 &[32, 84, 114, 97, 110, 115, 102, 101, 114],
             ];
@@ -444,6 +899,16 @@ pub mod vft {
         }
     }
 }
+#[derive(PartialEq, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct VestingInfo {
+    pub total: U256,
+    pub released: U256,
+    pub start_block: u32,
+    pub cliff: u32,
+    pub duration: u32,
+}
 pub mod traits {
     use super::*;
     #[allow(dead_code)]
@@ -456,21 +921,55 @@ pub mod traits {
             name: String,
             symbol: String,
             decimals: u8,
+            max_supply: Option<U256>,
         ) -> impl Activation<Args = Self::Args>;
     }
     #[allow(clippy::type_complexity)]
     pub trait Vft {
         type Args;
+        fn accept_admin(&mut self) -> impl Call<Output = (), Args = Self::Args>;
+        fn batch_mint(
+            &mut self,
+            recipients: Vec<(ActorId, U256)>,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn batch_transfer(
+            &mut self,
+            recipients: Vec<(ActorId, U256)>,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
         fn burn(
             &mut self,
             from: ActorId,
             value: U256,
         ) -> impl Call<Output = bool, Args = Self::Args>;
-        fn grant_admin_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
+        fn create_vesting(
+            &mut self,
+            beneficiary: ActorId,
+            total: U256,
+            start_block: u32,
+            cliff: u32,
+            duration: u32,
+        ) -> impl Call<Output = (), Args = Self::Args>;
         fn grant_burner_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
         fn grant_minter_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
+        fn grant_pauser_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
         fn mint(&mut self, to: ActorId, value: U256)
             -> impl Call<Output = bool, Args = Self::Args>;
+        fn pause(&mut self) -> impl Call<Output = (), Args = Self::Args>;
+        fn permit(
+            &mut self,
+            owner: ActorId,
+            spender: ActorId,
+            value: U256,
+            deadline: u64,
+            nonce: u64,
+            signature: Vec<u8>,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn propose_admin(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
+        fn release(
+            &mut self,
+            from: ActorId,
+            beneficiary: ActorId,
+        ) -> impl Call<Output = U256, Args = Self::Args>;
         fn revoke_admin_role(&mut self, from: ActorId)
             -> impl Call<Output = (), Args = Self::Args>;
         fn revoke_burner_role(
@@ -481,11 +980,11 @@ pub mod traits {
             &mut self,
             from: ActorId,
         ) -> impl Call<Output = (), Args = Self::Args>;
-        fn approve(
+        fn revoke_pauser_role(
             &mut self,
-            spender: ActorId,
-            value: U256,
-        ) -> impl Call<Output = bool, Args = Self::Args>;
+            from: ActorId,
+        ) -> impl Call<Output = (), Args = Self::Args>;
+        fn snapshot(&mut self) -> impl Call<Output = u64, Args = Self::Args>;
         fn transfer(
             &mut self,
             to: ActorId,
@@ -497,9 +996,33 @@ pub mod traits {
             to: ActorId,
             value: U256,
         ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn unpause(&mut self) -> impl Call<Output = (), Args = Self::Args>;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
         fn admins(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn balance_of_at(
+            &self,
+            account: ActorId,
+            snapshot_id: u64,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
         fn burners(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn is_paused(&self) -> impl Query<Output = bool, Args = Self::Args>;
+        fn max_batch_size(&self) -> impl Query<Output = u32, Args = Self::Args>;
+        fn max_supply(&self) -> impl Query<Output = Option<U256>, Args = Self::Args>;
         fn minters(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = Self::Args>;
+        fn pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn pausers(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn total_supply_at(&self, snapshot_id: u64)
+            -> impl Query<Output = U256, Args = Self::Args>;
+        fn vesting_info(
+            &self,
+            from: ActorId,
+            beneficiary: ActorId,
+        ) -> impl Query<Output = Option<VestingInfo>, Args = Self::Args>;
         fn allowance(
             &self,
             owner: ActorId,
@@ -520,5 +1043,5 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub Vft<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Vft for Vft<A> { type Args = A; fn burn (&mut self, from: ActorId,value: U256,) -> MockCall<A, bool>;fn grant_admin_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_burner_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_minter_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn mint (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn revoke_admin_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_burner_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_minter_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn admins (& self, ) -> MockQuery<A, Vec<ActorId>>;fn burners (& self, ) -> MockQuery<A, Vec<ActorId>>;fn minters (& self, ) -> MockQuery<A, Vec<ActorId>>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>; } }
+    mock! { pub Vft<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Vft for Vft<A> { type Args = A; fn accept_admin (&mut self, ) -> MockCall<A, ()>;fn batch_mint (&mut self, recipients: Vec<(ActorId, U256)>,) -> MockCall<A, bool>;fn batch_transfer (&mut self, recipients: Vec<(ActorId, U256)>,) -> MockCall<A, bool>;fn burn (&mut self, from: ActorId,value: U256,) -> MockCall<A, bool>;fn create_vesting (&mut self, beneficiary: ActorId,total: U256,start_block: u32,cliff: u32,duration: u32,) -> MockCall<A, ()>;fn grant_burner_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_minter_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_pauser_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn mint (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn pause (&mut self, ) -> MockCall<A, ()>;fn permit (&mut self, owner: ActorId,spender: ActorId,value: U256,deadline: u64,nonce: u64,signature: Vec<u8>,) -> MockCall<A, bool>;fn propose_admin (&mut self, to: ActorId,) -> MockCall<A, ()>;fn release (&mut self, from: ActorId,beneficiary: ActorId,) -> MockCall<A, U256>;fn revoke_admin_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_burner_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_minter_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_pauser_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn snapshot (&mut self, ) -> MockCall<A, u64>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn unpause (&mut self, ) -> MockCall<A, ()>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn admins (& self, ) -> MockQuery<A, Vec<ActorId>>;fn balance_of_at (& self, account: ActorId,snapshot_id: u64,) -> MockQuery<A, U256>;fn burners (& self, ) -> MockQuery<A, Vec<ActorId>>;fn is_paused (& self, ) -> MockQuery<A, bool>;fn max_batch_size (& self, ) -> MockQuery<A, u32>;fn max_supply (& self, ) -> MockQuery<A, Option<U256>>;fn minters (& self, ) -> MockQuery<A, Vec<ActorId>>;fn nonces (& self, owner: ActorId,) -> MockQuery<A, u64>;fn pending_admin (& self, ) -> MockQuery<A, Option<ActorId>>;fn pausers (& self, ) -> MockQuery<A, Vec<ActorId>>;fn total_supply_at (& self, snapshot_id: u64,) -> MockQuery<A, U256>;fn vesting_info (& self, from: ActorId,beneficiary: ActorId,) -> MockQuery<A, Option<VestingInfo>>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>; } }
 }
@@ -8,8 +8,8 @@ pub struct Program(());
 
 #[program]
 impl Program {
-    pub fn new(name: String, symbol: String) -> Self {
-        ExtendedService::init(name, symbol);
+    pub fn new(name: String, symbol: String, soulbound: bool) -> Self {
+        ExtendedService::init(name, symbol, soulbound);
         Self(())
     }
 
@@ -12,17 +12,41 @@ pub fn mint(
     token_id: &mut TokenId,
     to: ActorId,
     token_metadata: TokenMetadata,
-) -> Result<()> {
-    owner_by_id.insert(*token_id, to);
+) -> Result<TokenId> {
+    let minted_token_id = *token_id;
+    owner_by_id.insert(minted_token_id, to);
     tokens_for_owner
         .entry(to)
         .and_modify(|tokens| {
-            tokens.insert(*token_id);
+            tokens.insert(minted_token_id);
         })
-        .or_insert_with(|| HashSet::from([*token_id]));
-    token_metadata_by_id.insert(*token_id, token_metadata);
+        .or_insert_with(|| HashSet::from([minted_token_id]));
+    token_metadata_by_id.insert(minted_token_id, token_metadata);
     *token_id += 1.into();
-    Ok(())
+    Ok(minted_token_id)
+}
+
+// token ids are assigned in order, the whole batch fails if any mint fails
+pub fn batch_mint(
+    owner_by_id: &mut HashMap<TokenId, ActorId>,
+    tokens_for_owner: &mut HashMap<ActorId, HashSet<TokenId>>,
+    token_metadata_by_id: &mut HashMap<TokenId, TokenMetadata>,
+    token_id: &mut TokenId,
+    recipients: Vec<(ActorId, TokenMetadata)>,
+) -> Result<Vec<TokenId>> {
+    recipients
+        .into_iter()
+        .map(|(to, token_metadata)| {
+            mint(
+                owner_by_id,
+                tokens_for_owner,
+                token_metadata_by_id,
+                token_id,
+                to,
+                token_metadata,
+            )
+        })
+        .collect()
 }
 
 pub fn burn(
@@ -31,7 +55,7 @@ pub fn burn(
     token_approvals: &mut HashMap<TokenId, ActorId>,
     token_metadata_by_id: &mut HashMap<TokenId, TokenMetadata>,
     token_id: TokenId,
-) -> Result<()> {
+) -> Result<ActorId> {
     let owner = owner_by_id
         .remove(&token_id)
         .ok_or(Error::TokenDoesNotExist)?;
@@ -43,5 +67,5 @@ pub fn burn(
     }
     token_approvals.remove(&token_id);
     token_metadata_by_id.remove(&token_id);
-    Ok(())
+    Ok(owner)
 }
@@ -15,7 +15,14 @@ pub struct ExtendedStorage {
     minters: HashSet<ActorId>,
     burners: HashSet<ActorId>,
     admins: HashSet<ActorId>,
+    pending_admin: Option<ActorId>,
     token_metadata_by_id: HashMap<TokenId, TokenMetadata>,
+    editors: HashSet<ActorId>,
+    // metadata of the whole collection can't be updated anymore
+    metadata_frozen: bool,
+    frozen_tokens: HashSet<TokenId>,
+    // tokens can only be handed out by minters, holders can't transfer them
+    soulbound: bool,
 }
 
 #[derive(Default, Debug, Encode, Decode, TypeInfo, Clone)]
@@ -26,6 +33,12 @@ pub struct TokenMetadata {
     pub reference: String, // URL to an off-chain JSON file with more info
 }
 
+// Max number of certificates minted by one batch_mint message.
+pub const MAX_BATCH_SIZE: usize = 200;
+
+// Max number of tokens returned by one page of tokens_for_owner and all_tokens.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
 static mut EXTENDED_STORAGE: Option<ExtendedStorage> = None;
 
 #[derive(Encode, Decode, TypeInfo)]
@@ -38,6 +51,24 @@ pub enum Event {
         from: ActorId,
         token_id: TokenId,
     },
+    AdminProposed {
+        from: ActorId,
+        to: ActorId,
+    },
+    AdminAccepted {
+        admin: ActorId,
+    },
+    AdminRevoked {
+        admin: ActorId,
+    },
+    MetadataUpdated {
+        token_id: TokenId,
+        token_metadata: TokenMetadata,
+    },
+    // token_id is None when metadata of the whole collection is frozen
+    MetadataFrozen {
+        token_id: Option<TokenId>,
+    },
 }
 #[derive(Clone)]
 pub struct ExtendedService {
@@ -45,13 +76,15 @@ pub struct ExtendedService {
 }
 
 impl ExtendedService {
-    pub fn init(name: String, symbol: String) -> Self {
+    pub fn init(name: String, symbol: String, soulbound: bool) -> Self {
         let admin = msg::source();
         unsafe {
             EXTENDED_STORAGE = Some(ExtendedStorage {
                 admins: [admin].into(),
                 minters: [admin].into(),
                 burners: [admin].into(),
+                editors: [admin].into(),
+                soulbound,
                 ..Default::default()
             });
         };
@@ -83,11 +116,12 @@ impl ExtendedService {
             vnft: VnftService::new(),
         }
     }
-    pub fn mint(&mut self, to: ActorId, token_metadata: TokenMetadata) {
+    pub fn mint(&mut self, to: ActorId, token_metadata: TokenMetadata) -> TokenId {
         if !self.get().minters.contains(&msg::source()) {
             panic!("Not allowed to mint")
         };
-        services::utils::panicking(|| {
+        Self::ensure_valid_recipient(to);
+        let token_id = services::utils::panicking(|| {
             funcs::mint(
                 Storage::owner_by_id(),
                 Storage::tokens_for_owner(),
@@ -99,13 +133,51 @@ impl ExtendedService {
         });
         self.notify_on(Event::Minted { to, token_metadata })
             .expect("Notification Error");
+        token_id
+    }
+
+    pub fn batch_mint(&mut self, recipients: Vec<(ActorId, TokenMetadata)>) -> Vec<TokenId> {
+        if !self.get().minters.contains(&msg::source()) {
+            panic!("Not allowed to mint")
+        };
+        if recipients.len() > MAX_BATCH_SIZE {
+            panic!("Batch too large")
+        };
+        for (to, _) in &recipients {
+            Self::ensure_valid_recipient(*to);
+        }
+        let token_ids = services::utils::panicking(|| {
+            funcs::batch_mint(
+                Storage::owner_by_id(),
+                Storage::tokens_for_owner(),
+                &mut self.get_mut().token_metadata_by_id,
+                &mut self.get_mut().token_id,
+                recipients.clone(),
+            )
+        });
+        for (to, token_metadata) in recipients {
+            self.notify_on(Event::Minted { to, token_metadata })
+                .expect("Notification Error");
+        }
+        token_ids
     }
 
+    // burners, the holder and the account approved for the token can burn it
     pub fn burn(&mut self, from: ActorId, token_id: TokenId) {
-        if !self.get().burners.contains(&msg::source()) {
+        let Some(&owner) = Storage::owner_by_id().get(&token_id) else {
+            panic!("Token does not exist")
+        };
+        if owner != from {
+            panic!("Not token owner")
+        };
+        let source = msg::source();
+        if source != owner
+            && Storage::token_approvals().get(&token_id) != Some(&source)
+            && !self.get().burners.contains(&source)
+        {
             panic!("Not allowed to burn")
         };
-        services::utils::panicking(|| {
+        let from = services::utils::panicking(|| {
             funcs::burn(
                 Storage::owner_by_id(),
                 Storage::tokens_for_owner(),
@@ -114,13 +186,83 @@ impl ExtendedService {
                 token_id,
             )
         });
+        self.get_mut().frozen_tokens.remove(&token_id);
         self.notify_on(Event::Burned { from, token_id })
             .expect("Notification Error");
     }
 
-    pub fn grant_admin_role(&mut self, to: ActorId) {
+    // soulbound tokens only leave the issuing minter, e.g. the claim from L2eTop
+    pub fn approve(&mut self, approved: ActorId, token_id: TokenId) {
+        self.ensure_transferable(msg::source());
+        self.vnft.approve(approved, token_id)
+    }
+    pub fn transfer(&mut self, to: ActorId, token_id: TokenId) {
+        self.ensure_transferable(msg::source());
+        self.vnft.transfer(to, token_id)
+    }
+    // approved account can only take a soulbound token to itself
+    pub fn transfer_from(&mut self, from: ActorId, to: ActorId, token_id: TokenId) {
+        let source = msg::source();
+        self.ensure_transferable(if to == source { from } else { source });
+        self.vnft.transfer_from(from, to, token_id)
+    }
+
+    pub fn update_metadata(&mut self, token_id: TokenId, token_metadata: TokenMetadata) {
+        if !self.get().editors.contains(&msg::source()) {
+            panic!("Not allowed to update metadata")
+        };
+        if self.is_metadata_frozen(token_id) {
+            panic!("Metadata is frozen")
+        };
+        let Some(metadata) = self.get_mut().token_metadata_by_id.get_mut(&token_id) else {
+            panic!("Token does not exist")
+        };
+        *metadata = token_metadata.clone();
+        self.notify_on(Event::MetadataUpdated {
+            token_id,
+            token_metadata,
+        })
+        .expect("Notification Error");
+    }
+
+    // freezes metadata of one token, or of the whole collection if token_id is None
+    pub fn freeze_metadata(&mut self, token_id: Option<TokenId>) {
+        if !self.get().editors.contains(&msg::source()) {
+            panic!("Not allowed to update metadata")
+        };
+        let storage = self.get_mut();
+        match token_id {
+            Some(token_id) => {
+                if !storage.token_metadata_by_id.contains_key(&token_id) {
+                    panic!("Token does not exist")
+                };
+                storage.frozen_tokens.insert(token_id);
+            }
+            None => storage.metadata_frozen = true,
+        }
+        self.notify_on(Event::MetadataFrozen { token_id })
+            .expect("Notification Error");
+    }
+
+    pub fn propose_admin(&mut self, to: ActorId) {
         self.ensure_is_admin();
-        self.get_mut().admins.insert(to);
+        self.get_mut().pending_admin = Some(to);
+        self.notify_on(Event::AdminProposed {
+            from: msg::source(),
+            to,
+        })
+        .expect("Notification Error");
+    }
+    pub fn accept_admin(&mut self) {
+        let admin = msg::source();
+        if self.get().pending_admin != Some(admin) {
+            panic!("Not pending admin")
+        };
+        let storage = self.get_mut();
+        storage.pending_admin = None;
+        storage.admins.insert(admin);
+        self.notify_on(Event::AdminAccepted { admin })
+            .expect("Notification Error");
     }
     pub fn grant_minter_role(&mut self, to: ActorId) {
         self.ensure_is_admin();
@@ -130,10 +272,21 @@ impl ExtendedService {
         self.ensure_is_admin();
         self.get_mut().burners.insert(to);
     }
+    pub fn grant_editor_role(&mut self, to: ActorId) {
+        self.ensure_is_admin();
+        self.get_mut().editors.insert(to);
+    }
 
     pub fn revoke_admin_role(&mut self, from: ActorId) {
         self.ensure_is_admin();
-        self.get_mut().admins.remove(&from);
+        let admins = &mut self.get_mut().admins;
+        if admins.len() == 1 && admins.contains(&from) {
+            panic!("Last admin")
+        };
+        if admins.remove(&from) {
+            self.notify_on(Event::AdminRevoked { admin: from })
+                .expect("Notification Error");
+        }
     }
     pub fn revoke_minter_role(&mut self, from: ActorId) {
         self.ensure_is_admin();
@@ -143,6 +296,10 @@ impl ExtendedService {
         self.ensure_is_admin();
         self.get_mut().burners.remove(&from);
     }
+    pub fn revoke_editor_role(&mut self, from: ActorId) {
+        self.ensure_is_admin();
+        self.get_mut().editors.remove(&from);
+    }
     pub fn minters(&self) -> Vec<ActorId> {
         self.get().minters.clone().into_iter().collect()
     }
@@ -154,12 +311,55 @@ impl ExtendedService {
     pub fn admins(&self) -> Vec<ActorId> {
         self.get().admins.clone().into_iter().collect()
     }
+
+    pub fn pending_admin(&self) -> Option<ActorId> {
+        self.get().pending_admin
+    }
     pub fn token_id(&self) -> TokenId {
         self.get().token_id
     }
     pub fn token_metadata_by_id(&self, token_id: TokenId) -> Option<TokenMetadata> {
         self.get().token_metadata_by_id.get(&token_id).cloned()
     }
+
+    pub fn total_supply(&self) -> U256 {
+        self.get().token_metadata_by_id.len().into()
+    }
+
+    // tokens are ordered by id, a page holds at most MAX_PAGE_SIZE tokens
+    pub fn tokens_for_owner(
+        &self,
+        owner: ActorId,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(TokenId, TokenMetadata)> {
+        let token_ids = Storage::tokens_for_owner()
+            .get(&owner)
+            .map(|tokens| tokens.iter().copied().collect())
+            .unwrap_or_default();
+        self.page(token_ids, offset, limit)
+    }
+
+    pub fn all_tokens(&self, offset: u32, limit: u32) -> Vec<(TokenId, TokenMetadata)> {
+        let token_ids = self.get().token_metadata_by_id.keys().copied().collect();
+        self.page(token_ids, offset, limit)
+    }
+
+    pub fn is_soulbound(&self) -> bool {
+        self.get().soulbound
+    }
+
+    pub fn editors(&self) -> Vec<ActorId> {
+        self.get().editors.clone().into_iter().collect()
+    }
+
+    pub fn is_metadata_frozen(&self, token_id: TokenId) -> bool {
+        self.get().metadata_frozen || self.get().frozen_tokens.contains(&token_id)
+    }
+
+    pub fn max_batch_size(&self) -> u32 {
+        MAX_BATCH_SIZE as u32
+    }
 }
 
 impl ExtendedService {
@@ -168,6 +368,34 @@ impl ExtendedService {
             panic!("Not admin")
         };
     }
+    fn page(
+        &self,
+        mut token_ids: Vec<TokenId>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(TokenId, TokenMetadata)> {
+        token_ids.sort_unstable();
+        let metadata = &self.get().token_metadata_by_id;
+        token_ids
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PAGE_SIZE) as usize)
+            .map(|token_id| {
+                let token_metadata = metadata.get(&token_id).cloned().unwrap_or_default();
+                (token_id, token_metadata)
+            })
+            .collect()
+    }
+    fn ensure_valid_recipient(to: ActorId) {
+        if to == ActorId::zero() {
+            panic!("Invalid recipient")
+        };
+    }
+    fn ensure_transferable(&self, owner: ActorId) {
+        if self.get().soulbound && !self.get().minters.contains(&owner) {
+            panic!("Soulbound token is not transferable")
+        };
+    }
 }
 impl AsRef<VnftService> for ExtendedService {
     fn as_ref(&self) -> &VnftService {
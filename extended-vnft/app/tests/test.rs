@@ -18,6 +18,7 @@ async fn test_basic_function() {
         .new(
             "collection_name".to_string(),
             "collection_symbol".to_string(),
+            false,
         )
         .send_recv(code_id, "123")
         .await
@@ -31,11 +32,12 @@ async fn test_basic_function() {
         media: "token_media".to_string(),
         reference: "token_reference".to_string(),
     };
-    client
+    let minted_token_id = client
         .mint(100.into(), metadata)
         .send_recv(extended_vnft_id)
         .await
         .unwrap();
+    assert_eq!(minted_token_id, 0.into());
     // check balance
     let balance = client
         .balance_of(100.into())
@@ -125,7 +127,7 @@ async fn test_grant_role() {
 
     let extended_vft_factory = Factory::new(program_space.clone());
     let extended_vft_id = extended_vft_factory
-        .new("name".to_string(), "symbol".to_string())
+        .new("name".to_string(), "symbol".to_string(), false)
         .send_recv(code_id, "123")
         .await
         .unwrap();
@@ -173,10 +175,10 @@ async fn test_grant_role() {
         .unwrap();
     assert_eq!(balance, 1.into());
 
-    // try burner role
+    // try burner role, 102 is neither a burner nor the holder
     let res = client
         .burn(101.into(), 0.into())
-        .with_args(GTestArgs::new(101.into()))
+        .with_args(GTestArgs::new(102.into()))
         .send_recv(extended_vft_id)
         .await;
     assert!(res.is_err());
@@ -202,12 +204,29 @@ async fn test_grant_role() {
         .unwrap();
     assert_eq!(balance, 0.into());
 
-    // grant admin role
+    // propose admin
     client
-        .grant_admin_role(101.into())
+        .propose_admin(101.into())
         .send_recv(extended_vft_id)
         .await
         .unwrap();
+    let pending = client.pending_admin().recv(extended_vft_id).await.unwrap();
+    assert_eq!(pending, Some(101.into()));
+    // only the proposed admin can accept
+    let res = client
+        .accept_admin()
+        .with_args(GTestArgs::new(102.into()))
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
+    client
+        .accept_admin()
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vft_id)
+        .await
+        .unwrap();
+    let pending = client.pending_admin().recv(extended_vft_id).await.unwrap();
+    assert_eq!(pending, None);
     let admins = client.admins().recv(extended_vft_id).await.unwrap();
     assert_eq!(admins, vec![100.into(), 101.into()]);
 
@@ -219,6 +238,12 @@ async fn test_grant_role() {
         .unwrap();
     let admins = client.admins().recv(extended_vft_id).await.unwrap();
     assert_eq!(admins, vec![100.into()]);
+    // the last admin can't be removed
+    let res = client
+        .revoke_admin_role(100.into())
+        .send_recv(extended_vft_id)
+        .await;
+    assert!(res.is_err());
     client
         .revoke_minter_role(101.into())
         .send_recv(extended_vft_id)
@@ -234,3 +259,421 @@ async fn test_grant_role() {
     let burners = client.burners().recv(extended_vft_id).await.unwrap();
     assert_eq!(burners, vec![100.into()]);
 }
+
+fn token_metadata(name: &str) -> TokenMetadata {
+    TokenMetadata {
+        name: name.to_string(),
+        description: "token_description".to_string(),
+        media: "token_media".to_string(),
+        reference: "token_reference".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_update_and_freeze_metadata() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vnft_wasm.opt.wasm");
+
+    let extended_vnft_factory = Factory::new(program_space.clone());
+    let extended_vnft_id = extended_vnft_factory
+        .new(
+            "collection_name".to_string(),
+            "collection_symbol".to_string(),
+            false,
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    let mut client = VftClient::new(program_space);
+    for name in ["first", "second"] {
+        client
+            .mint(100.into(), token_metadata(name))
+            .send_recv(extended_vnft_id)
+            .await
+            .unwrap();
+    }
+
+    // only editors can update metadata
+    let res = client
+        .update_metadata(0.into(), token_metadata("fixed"))
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    client
+        .grant_editor_role(101.into())
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    client
+        .update_metadata(0.into(), token_metadata("fixed"))
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let metadata = client
+        .token_metadata_by_id(0.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(metadata, Some(token_metadata("fixed")));
+
+    // frozen token can't be updated
+    client
+        .freeze_metadata(Some(0.into()))
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let res = client
+        .update_metadata(0.into(), token_metadata("again"))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    let frozen = client
+        .is_metadata_frozen(1.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert!(!frozen);
+    client
+        .update_metadata(1.into(), token_metadata("fixed"))
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+
+    // frozen collection
+    client
+        .freeze_metadata(None)
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let frozen = client
+        .is_metadata_frozen(1.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert!(frozen);
+    let res = client
+        .update_metadata(1.into(), token_metadata("again"))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_soulbound() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vnft_wasm.opt.wasm");
+
+    let extended_vnft_factory = Factory::new(program_space.clone());
+    let extended_vnft_id = extended_vnft_factory
+        .new(
+            "collection_name".to_string(),
+            "collection_symbol".to_string(),
+            true,
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    let mut client = VftClient::new(program_space);
+    let soulbound = client.is_soulbound().recv(extended_vnft_id).await.unwrap();
+    assert!(soulbound);
+    for name in ["first", "second"] {
+        client
+            .mint(100.into(), token_metadata(name))
+            .send_recv(extended_vnft_id)
+            .await
+            .unwrap();
+    }
+
+    // minter hands out the certificate
+    client
+        .approve(101.into(), 0.into())
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let res = client
+        .transfer_from(100.into(), 103.into(), 0.into())
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    client
+        .transfer_from(100.into(), 101.into(), 0.into())
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    client
+        .transfer(102.into(), 1.into())
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+
+    // holders can't transfer or approve it
+    let res = client
+        .transfer(103.into(), 0.into())
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    let res = client
+        .approve(103.into(), 1.into())
+        .with_args(GTestArgs::new(102.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    let owner = client
+        .owner_of(0.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(owner, 101.into());
+
+    // burner can still burn it
+    client
+        .burn(101.into(), 0.into())
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let balance = client
+        .balance_of(101.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 0.into());
+}
+
+#[tokio::test]
+async fn test_enumeration() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vnft_wasm.opt.wasm");
+
+    let extended_vnft_factory = Factory::new(program_space.clone());
+    let extended_vnft_id = extended_vnft_factory
+        .new(
+            "collection_name".to_string(),
+            "collection_symbol".to_string(),
+            false,
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    let mut client = VftClient::new(program_space);
+    for (to, name) in [(101_u64, "first"), (102, "second"), (101, "third")] {
+        client
+            .mint(to.into(), token_metadata(name))
+            .send_recv(extended_vnft_id)
+            .await
+            .unwrap();
+    }
+
+    let total_supply = client.total_supply().recv(extended_vnft_id).await.unwrap();
+    assert_eq!(total_supply, 3.into());
+
+    let tokens = client
+        .tokens_for_owner(101.into(), 0, 10)
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            (0.into(), token_metadata("first")),
+            (2.into(), token_metadata("third"))
+        ]
+    );
+    let tokens = client
+        .tokens_for_owner(103.into(), 0, 10)
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert!(tokens.is_empty());
+
+    // pages of all tokens
+    let tokens = client
+        .all_tokens(0, 2)
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            (0.into(), token_metadata("first")),
+            (1.into(), token_metadata("second"))
+        ]
+    );
+    let tokens = client
+        .all_tokens(2, 2)
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(tokens, vec![(2.into(), token_metadata("third"))]);
+}
+
+#[tokio::test]
+async fn test_batch_mint() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vnft_wasm.opt.wasm");
+
+    let extended_vnft_factory = Factory::new(program_space.clone());
+    let extended_vnft_id = extended_vnft_factory
+        .new(
+            "collection_name".to_string(),
+            "collection_symbol".to_string(),
+            false,
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    let mut client = VftClient::new(program_space);
+    let max_batch_size = client
+        .max_batch_size()
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let token_ids = client
+        .batch_mint(vec![
+            (101.into(), token_metadata("first")),
+            (102.into(), token_metadata("second")),
+        ])
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(token_ids, vec![0.into(), 1.into()]);
+    let owner = client
+        .owner_of(1.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(owner, 102.into());
+
+    // whole batch fails on an invalid recipient
+    let res = client
+        .batch_mint(vec![
+            (103.into(), token_metadata("third")),
+            (0.into(), token_metadata("fourth")),
+        ])
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    let total_supply = client.total_supply().recv(extended_vnft_id).await.unwrap();
+    assert_eq!(total_supply, 2.into());
+
+    // only minters can batch mint
+    let res = client
+        .batch_mint(vec![(101.into(), token_metadata("third"))])
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+
+    // full batch fits in one message
+    let recipients: Vec<_> = (0..max_batch_size as u64)
+        .map(|i| ((1_000 + i).into(), token_metadata("cohort")))
+        .collect();
+    let token_ids = client
+        .batch_mint(recipients)
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(token_ids.len(), max_batch_size as usize);
+    let total_supply = client.total_supply().recv(extended_vnft_id).await.unwrap();
+    assert_eq!(total_supply, (2 + max_batch_size).into());
+
+    // batch size is limited
+    let recipients: Vec<_> = (0..=max_batch_size as u64)
+        .map(|i| ((2_000 + i).into(), token_metadata("cohort")))
+        .collect();
+    let res = client
+        .batch_mint(recipients)
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+    let token_id = client.token_id().recv(extended_vnft_id).await.unwrap();
+    assert_eq!(token_id, (2 + max_batch_size).into());
+}
+
+#[tokio::test]
+async fn test_burn_checks_owner() {
+    let program_space = GTestRemoting::new(100.into());
+    program_space.system().init_logger();
+    let code_id = program_space
+        .system()
+        .submit_code_file("../target/wasm32-unknown-unknown/release/extended_vnft_wasm.opt.wasm");
+
+    let extended_vnft_factory = Factory::new(program_space.clone());
+    let extended_vnft_id = extended_vnft_factory
+        .new(
+            "collection_name".to_string(),
+            "collection_symbol".to_string(),
+            true,
+        )
+        .send_recv(code_id, "123")
+        .await
+        .unwrap();
+
+    let mut client = VftClient::new(program_space);
+    client
+        .batch_mint(vec![
+            (101.into(), token_metadata("first")),
+            (102.into(), token_metadata("second")),
+        ])
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+
+    // from must be the holder, even for burners
+    let res = client
+        .burn(102.into(), 0.into())
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+
+    // other holders can't burn it
+    let res = client
+        .burn(101.into(), 0.into())
+        .with_args(GTestArgs::new(102.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+
+    // holder burns a soulbound token without the burner role
+    client
+        .burn(101.into(), 0.into())
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await
+        .unwrap();
+    let balance = client
+        .balance_of(101.into())
+        .recv(extended_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 0.into());
+    let res = client
+        .burn(101.into(), 0.into())
+        .with_args(GTestArgs::new(101.into()))
+        .send_recv(extended_vnft_id)
+        .await;
+    assert!(res.is_err());
+
+    let total_supply = client.total_supply().recv(extended_vnft_id).await.unwrap();
+    assert_eq!(total_supply, 1.into());
+}
@@ -48,7 +48,7 @@ pub async fn get_new_client(api: &GearApi, name: &str) -> GearApi {
 }
 
 pub async fn init(api: &GearApi) -> (MessageId, ProgramId) {
-    let constructor = ("Name".to_string(), "Symbol".to_string(), 10_u8);
+    let constructor = ("Name".to_string(), "Symbol".to_string(), false);
     let request = ["New".encode(), constructor.encode()].concat();
 
     let path = "../target/wasm32-unknown-unknown/release/extended_vnft_wasm.opt.wasm";
@@ -19,8 +19,16 @@ impl<R> VftFactory<R> {
 }
 impl<R: Remoting + Clone> traits::VftFactory for VftFactory<R> {
     type Args = R::Args;
-    fn new(&self, name: String, symbol: String) -> impl Activation<Args = R::Args> {
-        RemotingAction::<_, vft_factory::io::New>::new(self.remoting.clone(), (name, symbol))
+    fn new(
+        &self,
+        name: String,
+        symbol: String,
+        soulbound: bool,
+    ) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, vft_factory::io::New>::new(
+            self.remoting.clone(),
+            (name, symbol, soulbound),
+        )
     }
 }
 pub mod vft_factory {
@@ -31,13 +39,13 @@ pub mod vft_factory {
         pub struct New(());
         impl New {
             #[allow(dead_code)]
-            pub fn encode_call(name: String, symbol: String) -> Vec<u8> {
-                <New as ActionIo>::encode_call(&(name, symbol))
+            pub fn encode_call(name: String, symbol: String, soulbound: bool) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&(name, symbol, soulbound))
             }
         }
         impl ActionIo for New {
             const ROUTE: &'static [u8] = &[12, 78, 101, 119];
-            type Params = (String, String);
+            type Params = (String, String, bool);
             type Reply = ();
         }
     }
@@ -52,15 +60,37 @@ impl<R> Vnft<R> {
 }
 impl<R: Remoting + Clone> traits::Vnft for Vnft<R> {
     type Args = R::Args;
+    fn accept_admin(&mut self) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::AcceptAdmin>::new(self.remoting.clone(), ())
+    }
+    fn approve(
+        &mut self,
+        approved: ActorId,
+        token_id: U256,
+    ) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::Approve>::new(self.remoting.clone(), (approved, token_id))
+    }
+    fn batch_mint(
+        &mut self,
+        recipients: Vec<(ActorId, TokenMetadata)>,
+    ) -> impl Call<Output = Vec<U256>, Args = R::Args> {
+        RemotingAction::<_, vnft::io::BatchMint>::new(self.remoting.clone(), recipients)
+    }
     fn burn(&mut self, from: ActorId, token_id: U256) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::Burn>::new(self.remoting.clone(), (from, token_id))
     }
-    fn grant_admin_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
-        RemotingAction::<_, vnft::io::GrantAdminRole>::new(self.remoting.clone(), to)
+    fn freeze_metadata(
+        &mut self,
+        token_id: Option<U256>,
+    ) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::FreezeMetadata>::new(self.remoting.clone(), token_id)
     }
     fn grant_burner_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::GrantBurnerRole>::new(self.remoting.clone(), to)
     }
+    fn grant_editor_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::GrantEditorRole>::new(self.remoting.clone(), to)
+    }
     fn grant_minter_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::GrantMinterRole>::new(self.remoting.clone(), to)
     }
@@ -68,25 +98,24 @@ impl<R: Remoting + Clone> traits::Vnft for Vnft<R> {
         &mut self,
         to: ActorId,
         token_metadata: TokenMetadata,
-    ) -> impl Call<Output = (), Args = R::Args> {
+    ) -> impl Call<Output = U256, Args = R::Args> {
         RemotingAction::<_, vnft::io::Mint>::new(self.remoting.clone(), (to, token_metadata))
     }
+    fn propose_admin(&mut self, to: ActorId) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::ProposeAdmin>::new(self.remoting.clone(), to)
+    }
     fn revoke_admin_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::RevokeAdminRole>::new(self.remoting.clone(), from)
     }
     fn revoke_burner_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::RevokeBurnerRole>::new(self.remoting.clone(), from)
     }
+    fn revoke_editor_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::RevokeEditorRole>::new(self.remoting.clone(), from)
+    }
     fn revoke_minter_role(&mut self, from: ActorId) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::RevokeMinterRole>::new(self.remoting.clone(), from)
     }
-    fn approve(
-        &mut self,
-        approved: ActorId,
-        token_id: U256,
-    ) -> impl Call<Output = (), Args = R::Args> {
-        RemotingAction::<_, vnft::io::Approve>::new(self.remoting.clone(), (approved, token_id))
-    }
     fn transfer(&mut self, to: ActorId, token_id: U256) -> impl Call<Output = (), Args = R::Args> {
         RemotingAction::<_, vnft::io::Transfer>::new(self.remoting.clone(), (to, token_id))
     }
@@ -101,15 +130,47 @@ impl<R: Remoting + Clone> traits::Vnft for Vnft<R> {
             (from, to, token_id),
         )
     }
+    fn update_metadata(
+        &mut self,
+        token_id: U256,
+        token_metadata: TokenMetadata,
+    ) -> impl Call<Output = (), Args = R::Args> {
+        RemotingAction::<_, vnft::io::UpdateMetadata>::new(
+            self.remoting.clone(),
+            (token_id, token_metadata),
+        )
+    }
     fn admins(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, vnft::io::Admins>::new(self.remoting.clone(), ())
     }
+    fn all_tokens(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<(U256, TokenMetadata)>, Args = R::Args> {
+        RemotingAction::<_, vnft::io::AllTokens>::new(self.remoting.clone(), (offset, limit))
+    }
     fn burners(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, vnft::io::Burners>::new(self.remoting.clone(), ())
     }
+    fn editors(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, vnft::io::Editors>::new(self.remoting.clone(), ())
+    }
+    fn is_metadata_frozen(&self, token_id: U256) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vnft::io::IsMetadataFrozen>::new(self.remoting.clone(), token_id)
+    }
+    fn is_soulbound(&self) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vnft::io::IsSoulbound>::new(self.remoting.clone(), ())
+    }
+    fn max_batch_size(&self) -> impl Query<Output = u32, Args = R::Args> {
+        RemotingAction::<_, vnft::io::MaxBatchSize>::new(self.remoting.clone(), ())
+    }
     fn minters(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, vnft::io::Minters>::new(self.remoting.clone(), ())
     }
+    fn pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, vnft::io::PendingAdmin>::new(self.remoting.clone(), ())
+    }
     fn token_id(&self) -> impl Query<Output = U256, Args = R::Args> {
         RemotingAction::<_, vnft::io::TokenId>::new(self.remoting.clone(), ())
     }
@@ -119,6 +180,20 @@ impl<R: Remoting + Clone> traits::Vnft for Vnft<R> {
     ) -> impl Query<Output = Option<TokenMetadata>, Args = R::Args> {
         RemotingAction::<_, vnft::io::TokenMetadataById>::new(self.remoting.clone(), token_id)
     }
+    fn tokens_for_owner(
+        &self,
+        owner: ActorId,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<(U256, TokenMetadata)>, Args = R::Args> {
+        RemotingAction::<_, vnft::io::TokensForOwner>::new(
+            self.remoting.clone(),
+            (owner, offset, limit),
+        )
+    }
+    fn total_supply(&self) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, vnft::io::TotalSupply>::new(self.remoting.clone(), ())
+    }
     fn balance_of(&self, owner: ActorId) -> impl Query<Output = U256, Args = R::Args> {
         RemotingAction::<_, vnft::io::BalanceOf>::new(self.remoting.clone(), owner)
     }
@@ -140,6 +215,47 @@ pub mod vnft {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AcceptAdmin(());
+        impl AcceptAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptAdmin {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 44, 65, 99, 99, 101, 112, 116, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = ();
+        }
+        pub struct Approve(());
+        impl Approve {
+            #[allow(dead_code)]
+            pub fn encode_call(approved: ActorId, token_id: U256) -> Vec<u8> {
+                <Approve as ActionIo>::encode_call(&(approved, token_id))
+            }
+        }
+        impl ActionIo for Approve {
+            const ROUTE: &'static [u8] =
+                &[16, 86, 110, 102, 116, 28, 65, 112, 112, 114, 111, 118, 101];
+            type Params = (ActorId, U256);
+            type Reply = ();
+        }
+        pub struct BatchMint(());
+        impl BatchMint {
+            #[allow(dead_code)]
+            pub fn encode_call(recipients: Vec<(ActorId, super::TokenMetadata)>) -> Vec<u8> {
+                <BatchMint as ActionIo>::encode_call(&recipients)
+            }
+        }
+        impl ActionIo for BatchMint {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 36, 66, 97, 116, 99, 104, 77, 105, 110, 116,
+            ];
+            type Params = Vec<(ActorId, super::TokenMetadata)>;
+            type Reply = Vec<U256>;
+        }
         pub struct Burn(());
         impl Burn {
             #[allow(dead_code)]
@@ -152,19 +268,19 @@ pub mod vnft {
             type Params = (ActorId, U256);
             type Reply = ();
         }
-        pub struct GrantAdminRole(());
-        impl GrantAdminRole {
+        pub struct FreezeMetadata(());
+        impl FreezeMetadata {
             #[allow(dead_code)]
-            pub fn encode_call(to: ActorId) -> Vec<u8> {
-                <GrantAdminRole as ActionIo>::encode_call(&to)
+            pub fn encode_call(token_id: Option<U256>) -> Vec<u8> {
+                <FreezeMetadata as ActionIo>::encode_call(&token_id)
             }
         }
-        impl ActionIo for GrantAdminRole {
+        impl ActionIo for FreezeMetadata {
             const ROUTE: &'static [u8] = &[
-                16, 86, 110, 102, 116, 56, 71, 114, 97, 110, 116, 65, 100, 109, 105, 110, 82, 111,
-                108, 101,
+                16, 86, 110, 102, 116, 56, 70, 114, 101, 101, 122, 101, 77, 101, 116, 97, 100, 97,
+                116, 97,
             ];
-            type Params = ActorId;
+            type Params = Option<U256>;
             type Reply = ();
         }
         pub struct GrantBurnerRole(());
@@ -182,6 +298,21 @@ pub mod vnft {
             type Params = ActorId;
             type Reply = ();
         }
+        pub struct GrantEditorRole(());
+        impl GrantEditorRole {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <GrantEditorRole as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for GrantEditorRole {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 60, 71, 114, 97, 110, 116, 69, 100, 105, 116, 111, 114, 82,
+                111, 108, 101,
+            ];
+            type Params = ActorId;
+            type Reply = ();
+        }
         pub struct GrantMinterRole(());
         impl GrantMinterRole {
             #[allow(dead_code)]
@@ -207,6 +338,20 @@ pub mod vnft {
         impl ActionIo for Mint {
             const ROUTE: &'static [u8] = &[16, 86, 110, 102, 116, 16, 77, 105, 110, 116];
             type Params = (ActorId, super::TokenMetadata);
+            type Reply = U256;
+        }
+        pub struct ProposeAdmin(());
+        impl ProposeAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <ProposeAdmin as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for ProposeAdmin {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 48, 80, 114, 111, 112, 111, 115, 101, 65, 100, 109, 105, 110,
+            ];
+            type Params = ActorId;
             type Reply = ();
         }
         pub struct RevokeAdminRole(());
@@ -239,32 +384,34 @@ pub mod vnft {
             type Params = ActorId;
             type Reply = ();
         }
-        pub struct RevokeMinterRole(());
-        impl RevokeMinterRole {
+        pub struct RevokeEditorRole(());
+        impl RevokeEditorRole {
             #[allow(dead_code)]
             pub fn encode_call(from: ActorId) -> Vec<u8> {
-                <RevokeMinterRole as ActionIo>::encode_call(&from)
+                <RevokeEditorRole as ActionIo>::encode_call(&from)
             }
         }
-        impl ActionIo for RevokeMinterRole {
+        impl ActionIo for RevokeEditorRole {
             const ROUTE: &'static [u8] = &[
-                16, 86, 110, 102, 116, 64, 82, 101, 118, 111, 107, 101, 77, 105, 110, 116, 101,
+                16, 86, 110, 102, 116, 64, 82, 101, 118, 111, 107, 101, 69, 100, 105, 116, 111,
                 114, 82, 111, 108, 101,
             ];
             type Params = ActorId;
             type Reply = ();
         }
-        pub struct Approve(());
-        impl Approve {
+        pub struct RevokeMinterRole(());
+        impl RevokeMinterRole {
             #[allow(dead_code)]
-            pub fn encode_call(approved: ActorId, token_id: U256) -> Vec<u8> {
-                <Approve as ActionIo>::encode_call(&(approved, token_id))
+            pub fn encode_call(from: ActorId) -> Vec<u8> {
+                <RevokeMinterRole as ActionIo>::encode_call(&from)
             }
         }
-        impl ActionIo for Approve {
-            const ROUTE: &'static [u8] =
-                &[16, 86, 110, 102, 116, 28, 65, 112, 112, 114, 111, 118, 101];
-            type Params = (ActorId, U256);
+        impl ActionIo for RevokeMinterRole {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 64, 82, 101, 118, 111, 107, 101, 77, 105, 110, 116, 101,
+                114, 82, 111, 108, 101,
+            ];
+            type Params = ActorId;
             type Reply = ();
         }
         pub struct Transfer(());
@@ -295,6 +442,21 @@ pub mod vnft {
             type Params = (ActorId, ActorId, U256);
             type Reply = ();
         }
+        pub struct UpdateMetadata(());
+        impl UpdateMetadata {
+            #[allow(dead_code)]
+            pub fn encode_call(token_id: U256, token_metadata: super::TokenMetadata) -> Vec<u8> {
+                <UpdateMetadata as ActionIo>::encode_call(&(token_id, token_metadata))
+            }
+        }
+        impl ActionIo for UpdateMetadata {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 56, 85, 112, 100, 97, 116, 101, 77, 101, 116, 97, 100, 97,
+                116, 97,
+            ];
+            type Params = (U256, super::TokenMetadata);
+            type Reply = ();
+        }
         pub struct Admins(());
         impl Admins {
             #[allow(dead_code)]
@@ -307,6 +469,20 @@ pub mod vnft {
             type Params = ();
             type Reply = Vec<ActorId>;
         }
+        pub struct AllTokens(());
+        impl AllTokens {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u32, limit: u32) -> Vec<u8> {
+                <AllTokens as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for AllTokens {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 36, 65, 108, 108, 84, 111, 107, 101, 110, 115,
+            ];
+            type Params = (u32, u32);
+            type Reply = Vec<(U256, super::TokenMetadata)>;
+        }
         pub struct Burners(());
         impl Burners {
             #[allow(dead_code)]
@@ -320,6 +496,62 @@ pub mod vnft {
             type Params = ();
             type Reply = Vec<ActorId>;
         }
+        pub struct Editors(());
+        impl Editors {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Editors as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Editors {
+            const ROUTE: &'static [u8] =
+                &[16, 86, 110, 102, 116, 28, 69, 100, 105, 116, 111, 114, 115];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct IsMetadataFrozen(());
+        impl IsMetadataFrozen {
+            #[allow(dead_code)]
+            pub fn encode_call(token_id: U256) -> Vec<u8> {
+                <IsMetadataFrozen as ActionIo>::encode_call(&token_id)
+            }
+        }
+        impl ActionIo for IsMetadataFrozen {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 64, 73, 115, 77, 101, 116, 97, 100, 97, 116, 97, 70, 114,
+                111, 122, 101, 110,
+            ];
+            type Params = U256;
+            type Reply = bool;
+        }
+        pub struct IsSoulbound(());
+        impl IsSoulbound {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <IsSoulbound as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for IsSoulbound {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 44, 73, 115, 83, 111, 117, 108, 98, 111, 117, 110, 100,
+            ];
+            type Params = ();
+            type Reply = bool;
+        }
+        pub struct MaxBatchSize(());
+        impl MaxBatchSize {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <MaxBatchSize as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for MaxBatchSize {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 48, 77, 97, 120, 66, 97, 116, 99, 104, 83, 105, 122, 101,
+            ];
+            type Params = ();
+            type Reply = u32;
+        }
         pub struct Minters(());
         impl Minters {
             #[allow(dead_code)]
@@ -333,6 +565,20 @@ pub mod vnft {
             type Params = ();
             type Reply = Vec<ActorId>;
         }
+        pub struct PendingAdmin(());
+        impl PendingAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <PendingAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for PendingAdmin {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 48, 80, 101, 110, 100, 105, 110, 103, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
         pub struct TokenId(());
         impl TokenId {
             #[allow(dead_code)]
@@ -361,6 +607,35 @@ pub mod vnft {
             type Params = U256;
             type Reply = Option<super::TokenMetadata>;
         }
+        pub struct TokensForOwner(());
+        impl TokensForOwner {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, offset: u32, limit: u32) -> Vec<u8> {
+                <TokensForOwner as ActionIo>::encode_call(&(owner, offset, limit))
+            }
+        }
+        impl ActionIo for TokensForOwner {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 56, 84, 111, 107, 101, 110, 115, 70, 111, 114, 79, 119, 110,
+                101, 114,
+            ];
+            type Params = (ActorId, u32, u32);
+            type Reply = Vec<(U256, super::TokenMetadata)>;
+        }
+        pub struct TotalSupply(());
+        impl TotalSupply {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TotalSupply as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TotalSupply {
+            const ROUTE: &'static [u8] = &[
+                16, 86, 110, 102, 116, 44, 84, 111, 116, 97, 108, 83, 117, 112, 112, 108, 121,
+            ];
+            type Params = ();
+            type Reply = U256;
+        }
         pub struct BalanceOf(());
         impl BalanceOf {
             #[allow(dead_code)]
@@ -443,6 +718,23 @@ pub mod vnft {
                 from: ActorId,
                 token_id: U256,
             },
+            AdminProposed {
+                from: ActorId,
+                to: ActorId,
+            },
+            AdminAccepted {
+                admin: ActorId,
+            },
+            AdminRevoked {
+                admin: ActorId,
+            },
+            MetadataUpdated {
+                token_id: U256,
+                token_metadata: TokenMetadata,
+            },
+            MetadataFrozen {
+                token_id: Option<U256>,
+            },
             Transfer {
                 from: ActorId,
                 to: ActorId,
@@ -459,6 +751,19 @@ pub mod vnft {
             const EVENT_NAMES: &'static [&'static [u8]] = &[
                 &[24, 77, 105, 110, 116, 101, 100],
                 &[24, 66, 117, 114, 110, 101, 100],
+                &[
+                    52, 65, 100, 109, 105, 110, 80, 114, 111, 112, 111, 115, 101, 100,
+                ],
+                &[
+                    52, 65, 100, 109, 105, 110, 65, 99, 99, 101, 112, 116, 101, 100,
+                ],
+                &[48, 65, 100, 109, 105, 110, 82, 101, 118, 111, 107, 101, 100],
+                &[
+                    60, 77, 101, 116, 97, 100, 97, 116, 97, 85, 112, 100, 97, 116, 101, 100,
+                ],
+                &[
+                    56, 77, 101, 116, 97, 100, 97, 116, 97, 70, 114, 111, 122, 101, 110,
+                ],
                 &[32, 84, 114, 97, 110, 115, 102, 101, 114],
                 &[32, 65, 112, 112, 114, 111, 118, 97, 108],
             ];
@@ -485,38 +790,57 @@ pub mod traits {
         type Args;
         #[allow(clippy::new_ret_no_self)]
         #[allow(clippy::wrong_self_convention)]
-        fn new(&self, name: String, symbol: String) -> impl Activation<Args = Self::Args>;
+        fn new(
+            &self,
+            name: String,
+            symbol: String,
+            soulbound: bool,
+        ) -> impl Activation<Args = Self::Args>;
     }
     #[allow(clippy::type_complexity)]
     pub trait Vnft {
         type Args;
+        fn accept_admin(&mut self) -> impl Call<Output = (), Args = Self::Args>;
+        fn approve(
+            &mut self,
+            approved: ActorId,
+            token_id: U256,
+        ) -> impl Call<Output = (), Args = Self::Args>;
+        fn batch_mint(
+            &mut self,
+            recipients: Vec<(ActorId, TokenMetadata)>,
+        ) -> impl Call<Output = Vec<U256>, Args = Self::Args>;
         fn burn(
             &mut self,
             from: ActorId,
             token_id: U256,
         ) -> impl Call<Output = (), Args = Self::Args>;
-        fn grant_admin_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
+        fn freeze_metadata(
+            &mut self,
+            token_id: Option<U256>,
+        ) -> impl Call<Output = (), Args = Self::Args>;
         fn grant_burner_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
+        fn grant_editor_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
         fn grant_minter_role(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
         fn mint(
             &mut self,
             to: ActorId,
             token_metadata: TokenMetadata,
-        ) -> impl Call<Output = (), Args = Self::Args>;
+        ) -> impl Call<Output = U256, Args = Self::Args>;
+        fn propose_admin(&mut self, to: ActorId) -> impl Call<Output = (), Args = Self::Args>;
         fn revoke_admin_role(&mut self, from: ActorId)
             -> impl Call<Output = (), Args = Self::Args>;
         fn revoke_burner_role(
             &mut self,
             from: ActorId,
         ) -> impl Call<Output = (), Args = Self::Args>;
-        fn revoke_minter_role(
+        fn revoke_editor_role(
             &mut self,
             from: ActorId,
         ) -> impl Call<Output = (), Args = Self::Args>;
-        fn approve(
+        fn revoke_minter_role(
             &mut self,
-            approved: ActorId,
-            token_id: U256,
+            from: ActorId,
         ) -> impl Call<Output = (), Args = Self::Args>;
         fn transfer(
             &mut self,
@@ -529,14 +853,39 @@ pub mod traits {
             to: ActorId,
             token_id: U256,
         ) -> impl Call<Output = (), Args = Self::Args>;
+        fn update_metadata(
+            &mut self,
+            token_id: U256,
+            token_metadata: TokenMetadata,
+        ) -> impl Call<Output = (), Args = Self::Args>;
         fn admins(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn all_tokens(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<(U256, TokenMetadata)>, Args = Self::Args>;
         fn burners(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn editors(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn is_metadata_frozen(
+            &self,
+            token_id: U256,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+        fn is_soulbound(&self) -> impl Query<Output = bool, Args = Self::Args>;
+        fn max_batch_size(&self) -> impl Query<Output = u32, Args = Self::Args>;
         fn minters(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
         fn token_id(&self) -> impl Query<Output = U256, Args = Self::Args>;
         fn token_metadata_by_id(
             &self,
             token_id: U256,
         ) -> impl Query<Output = Option<TokenMetadata>, Args = Self::Args>;
+        fn tokens_for_owner(
+            &self,
+            owner: ActorId,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<(U256, TokenMetadata)>, Args = Self::Args>;
+        fn total_supply(&self) -> impl Query<Output = U256, Args = Self::Args>;
         fn balance_of(&self, owner: ActorId) -> impl Query<Output = U256, Args = Self::Args>;
         fn get_approved(&self, token_id: U256) -> impl Query<Output = ActorId, Args = Self::Args>;
         fn name(&self) -> impl Query<Output = String, Args = Self::Args>;
@@ -552,5 +901,5 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub Vnft<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Vnft for Vnft<A> { type Args = A; fn burn (&mut self, from: ActorId,token_id: U256,) -> MockCall<A, ()>;fn grant_admin_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_burner_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_minter_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn mint (&mut self, to: ActorId,token_metadata: TokenMetadata,) -> MockCall<A, ()>;fn revoke_admin_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_burner_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_minter_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn approve (&mut self, approved: ActorId,token_id: U256,) -> MockCall<A, ()>;fn transfer (&mut self, to: ActorId,token_id: U256,) -> MockCall<A, ()>;fn transfer_from (&mut self, from: ActorId,to: ActorId,token_id: U256,) -> MockCall<A, ()>;fn admins (& self, ) -> MockQuery<A, Vec<ActorId>>;fn burners (& self, ) -> MockQuery<A, Vec<ActorId>>;fn minters (& self, ) -> MockQuery<A, Vec<ActorId>>;fn token_id (& self, ) -> MockQuery<A, U256>;fn token_metadata_by_id (& self, token_id: U256,) -> MockQuery<A, Option<TokenMetadata>>;fn balance_of (& self, owner: ActorId,) -> MockQuery<A, U256>;fn get_approved (& self, token_id: U256,) -> MockQuery<A, ActorId>;fn name (& self, ) -> MockQuery<A, String>;fn owner_of (& self, token_id: U256,) -> MockQuery<A, ActorId>;fn symbol (& self, ) -> MockQuery<A, String>; } }
+    mock! { pub Vnft<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Vnft for Vnft<A> { type Args = A; fn accept_admin (&mut self, ) -> MockCall<A, ()>;fn approve (&mut self, approved: ActorId,token_id: U256,) -> MockCall<A, ()>;fn batch_mint (&mut self, recipients: Vec<(ActorId, TokenMetadata)>,) -> MockCall<A, Vec<U256>>;fn burn (&mut self, from: ActorId,token_id: U256,) -> MockCall<A, ()>;fn freeze_metadata (&mut self, token_id: Option<U256>,) -> MockCall<A, ()>;fn grant_burner_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_editor_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn grant_minter_role (&mut self, to: ActorId,) -> MockCall<A, ()>;fn mint (&mut self, to: ActorId,token_metadata: TokenMetadata,) -> MockCall<A, U256>;fn propose_admin (&mut self, to: ActorId,) -> MockCall<A, ()>;fn revoke_admin_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_burner_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_editor_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn revoke_minter_role (&mut self, from: ActorId,) -> MockCall<A, ()>;fn transfer (&mut self, to: ActorId,token_id: U256,) -> MockCall<A, ()>;fn transfer_from (&mut self, from: ActorId,to: ActorId,token_id: U256,) -> MockCall<A, ()>;fn update_metadata (&mut self, token_id: U256,token_metadata: TokenMetadata,) -> MockCall<A, ()>;fn admins (& self, ) -> MockQuery<A, Vec<ActorId>>;fn all_tokens (& self, offset: u32,limit: u32,) -> MockQuery<A, Vec<(U256, TokenMetadata)>>;fn burners (& self, ) -> MockQuery<A, Vec<ActorId>>;fn editors (& self, ) -> MockQuery<A, Vec<ActorId>>;fn is_metadata_frozen (& self, token_id: U256,) -> MockQuery<A, bool>;fn is_soulbound (& self, ) -> MockQuery<A, bool>;fn max_batch_size (& self, ) -> MockQuery<A, u32>;fn minters (& self, ) -> MockQuery<A, Vec<ActorId>>;fn pending_admin (& self, ) -> MockQuery<A, Option<ActorId>>;fn token_id (& self, ) -> MockQuery<A, U256>;fn token_metadata_by_id (& self, token_id: U256,) -> MockQuery<A, Option<TokenMetadata>>;fn tokens_for_owner (& self, owner: ActorId,offset: u32,limit: u32,) -> MockQuery<A, Vec<(U256, TokenMetadata)>>;fn total_supply (& self, ) -> MockQuery<A, U256>;fn balance_of (& self, owner: ActorId,) -> MockQuery<A, U256>;fn get_approved (& self, token_id: U256,) -> MockQuery<A, ActorId>;fn name (& self, ) -> MockQuery<A, String>;fn owner_of (& self, token_id: U256,) -> MockQuery<A, ActorId>;fn symbol (& self, ) -> MockQuery<A, String>; } }
 }
@@ -1,32 +1,1603 @@
-use sails_rs::{calls::*, gtest::calls::*};
+use futures::StreamExt;
+use sails_rs::{calls::*, events::*, gtest::calls::*, ActorId, U256};
 
-use l2e_client::traits::*;
+use l2e_client::{
+    l2e::events::L2eEvents, traits::*, ContractInfo, ContractKind, L2eError, Operation, Role,
+};
+use vft_client::traits::{Vft as _, VftFactory as _};
+use vnft_client::traits::{VftFactory as _, Vnft as _};
 
 const ACTOR_ID: u64 = 42;
+const SPENDER_ID: u64 = 43;
+const ERC20_ID: u64 = 200;
+const ERC721_ID: u64 = 300;
+
+const VARA_UNIT: u128 = 1_000_000_000_000;
+
+async fn deploy_with(remoting: &GTestRemoting, erc20: ActorId, erc721: ActorId) -> ActorId {
+    // Submit program code into the system
+    let program_code_id = remoting.system().submit_code(l2e::WASM_BINARY);
+
+    let program_factory = l2e_client::L2eFactory::new(remoting.clone());
+
+    program_factory
+        .new(erc20, erc721) // Call program's constructor (see app/src/lib.rs)
+        .send_recv(program_code_id, b"salt")
+        .await
+        .unwrap()
+}
+
+async fn deploy(remoting: &GTestRemoting) -> ActorId {
+    deploy_with(remoting, ERC20_ID.into(), ERC721_ID.into()).await
+}
+
+async fn deploy_vft(remoting: &GTestRemoting) -> ActorId {
+    let code_id = remoting
+        .system()
+        .submit_code(extended_vft_wasm::WASM_BINARY);
+    vft_client::VftFactory::new(remoting.clone())
+        .new("L2E Token".to_string(), "L2E".to_string(), 12, None)
+        .send_recv(code_id, b"vft")
+        .await
+        .unwrap()
+}
+
+async fn deploy_vnft(remoting: &GTestRemoting) -> ActorId {
+    let code_id = remoting
+        .system()
+        .submit_code(extended_vnft_wasm::WASM_BINARY);
+    vnft_client::VftFactory::new(remoting.clone())
+        .new("L2E Certificate".to_string(), "L2EC".to_string(), true)
+        .send_recv(code_id, b"vnft")
+        .await
+        .unwrap()
+}
+
+// deploys VFT, VNFT and L2eTop, and lets L2eTop mint certificates
+async fn deploy_all(remoting: &GTestRemoting) -> (ActorId, ActorId, ActorId) {
+    let vft_id = deploy_vft(remoting).await;
+    let vnft_id = deploy_vnft(remoting).await;
+    let program_id = deploy_with(remoting, vft_id, vnft_id).await;
+
+    vnft_client::Vnft::new(remoting.clone())
+        .grant_minter_role(program_id)
+        .send_recv(vnft_id)
+        .await
+        .unwrap();
+
+    (program_id, vft_id, vnft_id)
+}
+
+#[tokio::test]
+async fn state_persists_between_messages() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let program_id = deploy(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    // constructor seeded storage is visible to later messages
+    let erc20_address = service_client
+        .get_erc20_address()
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(erc20_address, Ok(vec![ERC20_ID.into()]));
+
+    let admins = service_client
+        .role_members(Role::Admin)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(admins, Ok(vec![ACTOR_ID.into()]));
+
+    // approve without tokens, so no cross contract call is required
+    let approved = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            U256::zero(),
+            U256::zero(),
+        )
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(approved, Ok((0, U256::zero())));
+
+    // spender sees the approval in a later message
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![(ACTOR_ID.into(), 0, U256::zero())]));
+
+    // contracts are looked up by address, unknown ones are rejected
+    let res = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC721_ID.into(),
+            U256::zero(),
+            U256::zero(),
+        )
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::UnknownContract));
+
+    // a second approval for the same pair is rejected since the first one was stored
+    let res = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            U256::zero(),
+            U256::zero(),
+        )
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::BalancesAlreadyApproved));
+}
+
+#[tokio::test]
+async fn admin_actions_emit_events() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let program_id = deploy(&remoting).await;
+    let vft_id = deploy_vft(&remoting).await;
+    let vnft_id = deploy_vnft(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut listener = l2e_client::l2e::events::listener(remoting.clone());
+    let mut events = listener.listen().await.unwrap();
+
+    service_client
+        .grant_role(Role::Motivator, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let event = events.next().await.unwrap();
+    assert_eq!(
+        event,
+        (
+            program_id,
+            L2eEvents::RoleGranted {
+                role: Role::Motivator,
+                to: SPENDER_ID.into()
+            }
+        )
+    );
+
+    service_client
+        .register_vft(vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let event = events.next().await.unwrap();
+    assert_eq!(
+        event,
+        (
+            program_id,
+            L2eEvents::ContractRegistered {
+                address: vft_id,
+                kind: ContractKind::Vft
+            }
+        )
+    );
+    service_client
+        .register_vnft(vnft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let event = events.next().await.unwrap();
+    assert_eq!(
+        event,
+        (
+            program_id,
+            L2eEvents::ContractRegistered {
+                address: vnft_id,
+                kind: ContractKind::Vnft
+            }
+        )
+    );
+
+    // metadata is read from the registered contracts
+    let contract = service_client
+        .get_contract(vft_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        contract,
+        Ok(ContractInfo {
+            kind: ContractKind::Vft,
+            symbol: "L2E".to_string(),
+            decimals: 12,
+            enabled: true
+        })
+    );
+    let contract = service_client
+        .get_contract(vnft_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        contract,
+        Ok(ContractInfo {
+            kind: ContractKind::Vnft,
+            symbol: "L2EC".to_string(),
+            decimals: 0,
+            enabled: true
+        })
+    );
+
+    service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            U256::zero(),
+            U256::zero(),
+        )
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let event = events.next().await.unwrap();
+    assert_eq!(
+        event,
+        (
+            program_id,
+            L2eEvents::BalancesApproved {
+                owner: ACTOR_ID.into(),
+                spender: SPENDER_ID.into(),
+                vara: 0,
+                tokens: U256::zero(),
+                erc20: ERC20_ID.into()
+            }
+        )
+    );
+}
+
+#[tokio::test]
+async fn campaign_stages_are_claimed_in_order() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let program_id = deploy(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    let campaign_id = service_client
+        .create_campaign(ERC20_ID.into(), ERC721_ID.into(), 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(campaign_id, 0);
+
+    let stage = service_client
+        .add_stage(campaign_id, 0, 10.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(stage, Ok(0));
+    let stage = service_client
+        .add_stage(campaign_id, 0, 20.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(stage, Ok(1));
+
+    let campaign = service_client
+        .get_campaign(campaign_id)
+        .recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(campaign.motivator, ACTOR_ID.into());
+    assert_eq!(campaign.erc20, ERC20_ID.into());
+    assert_eq!(campaign.stages.len(), 2);
+    assert_eq!(campaign.stages[1].token_value, 20.into());
+
+    // only the motivator can extend the campaign
+    let res = service_client
+        .add_stage(campaign_id, 0, 30.into())
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoAuthToUpdateCampaign));
+
+    // stage has to reward something
+    let res = service_client
+        .add_stage(campaign_id, 0, U256::zero())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::EmptyStage));
+
+    // unknown contracts can not be used by a campaign
+    let res = service_client
+        .create_campaign(ERC721_ID.into(), ERC20_ID.into(), 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::UnknownContract));
+
+    // stage 1 can not be claimed before stage 0
+    let res = service_client
+        .transfer_balances_from(campaign_id, 1)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::PreviousStageNotClaimed));
+    let claimed = service_client
+        .get_claimed_stages(campaign_id, SPENDER_ID.into())
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claimed, Ok(0));
+}
+
+#[tokio::test]
+async fn minted_nft_is_approved_and_claimed_by_spender() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, _, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vnft = vnft_client::Vnft::new(remoting.clone());
+
+    let token_id = service_client
+        .mint_approve_nft(vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(token_id, U256::zero());
+
+    // recorded token id is the one assigned by the VNFT contract
+    let allowance = service_client
+        .get_spender_nft_allowances(ACTOR_ID.into())
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Ok(token_id));
+
+    let owner = vnft.owner_of(token_id).recv(vnft_id).await.unwrap();
+    assert_eq!(owner, program_id);
+    let approved = vnft.get_approved(token_id).recv(vnft_id).await.unwrap();
+    assert_eq!(approved, SPENDER_ID.into());
+
+    // spender claims the nft
+    let claimed = service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claimed, Ok(()));
+    let owner = vnft.owner_of(token_id).recv(vnft_id).await.unwrap();
+    assert_eq!(owner, SPENDER_ID.into());
+
+    // certificate is soulbound, spender can't pass it on
+    let res = vnft
+        .transfer(44.into(), token_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(vnft_id)
+        .await;
+    assert!(res.is_err());
+
+    let claims = service_client
+        .get_all_spender_claimed_for_owner()
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claims, Ok(vec![(SPENDER_ID.into(), token_id, true)]));
+
+    // nft can not be claimed twice
+    let res = service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::AlreadyClaimedNFT));
+}
+
+#[tokio::test]
+async fn nft_is_claimed_from_its_own_collection() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vnft = vnft_client::Vnft::new(remoting.clone());
+
+    // second collection, its token ids overlap with the first one
+    let code_id = remoting
+        .system()
+        .submit_code(extended_vnft_wasm::WASM_BINARY);
+    let other_vnft_id = vnft_client::VftFactory::new(remoting.clone())
+        .new("Other Certificate".to_string(), "OTHC".to_string(), false)
+        .send_recv(code_id, b"other_vnft")
+        .await
+        .unwrap();
+    vnft.grant_minter_role(program_id)
+        .send_recv(other_vnft_id)
+        .await
+        .unwrap();
+    service_client
+        .register_vnft(other_vnft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let token_id = service_client
+        .mint_approve_nft(vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let other_token_id = service_client
+        .mint_approve_nft(other_vnft_id, 44.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(token_id, other_token_id);
+
+    // spender can't take the same token id from another collection
+    let res = service_client
+        .transfer_nft_from(ACTOR_ID.into(), other_vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoExistNFTApprove));
+    let owner = vnft
+        .owner_of(other_token_id)
+        .recv(other_vnft_id)
+        .await
+        .unwrap();
+    assert_eq!(owner, program_id);
+
+    service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let owner = vnft.owner_of(token_id).recv(vnft_id).await.unwrap();
+    assert_eq!(owner, SPENDER_ID.into());
+
+    // certificate of another collection doesn't unlock campaign rewards
+    let campaign_id = service_client
+        .create_campaign(vft_id, other_vnft_id, 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 0, 10.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .transfer_balances_from(campaign_id, 0)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoExistNFTApprove));
+
+    service_client
+        .mint_approve_nft(other_vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .transfer_balances_from(campaign_id, 0)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoClaimedNFT));
+}
+
+#[tokio::test]
+async fn campaign_stage_claim_transfers_tokens() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vft = vft_client::Vft::new(remoting.clone());
+
+    // motivator funds and lets L2eTop pull the rewards
+    vft.mint(ACTOR_ID.into(), 100_000.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+    vft.approve(program_id, 30.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+
+    service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .mint_approve_nft(vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let campaign_id = service_client
+        .create_campaign(vft_id, vnft_id, 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 0, 10.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 0, 20.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // stage rewards require the certificate to be claimed first
+    let res = service_client
+        .transfer_balances_from(campaign_id, 0)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoClaimedNFT));
+
+    service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    for stage in 0..2 {
+        let claimed = service_client
+            .transfer_balances_from(campaign_id, stage)
+            .with_args(GTestArgs::new(SPENDER_ID.into()))
+            .send_recv(program_id)
+            .await
+            .unwrap();
+        assert_eq!(claimed, Ok(()));
+    }
+
+    let balance = vft
+        .balance_of(SPENDER_ID.into())
+        .recv(vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 30.into());
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![(ACTOR_ID.into(), 0, U256::zero())]));
+}
+
+#[tokio::test]
+async fn stage_claims_of_spender_are_not_interleaved() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vft = vft_client::Vft::new(remoting.clone());
+
+    vft.mint(ACTOR_ID.into(), 100_000.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+    vft.approve(program_id, 30.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+
+    service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .mint_approve_nft(vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let campaign_id = service_client
+        .create_campaign(vft_id, vnft_id, 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 0, 10.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 0, 20.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // both claims run in one block, the second one while the first waits for VFT
+    let program = remoting.system().get_program(program_id).unwrap();
+    let first = program.send_bytes(
+        SPENDER_ID,
+        l2e_client::l2e::io::TransferBalancesFrom::encode_call(campaign_id, 0),
+    );
+    let second = program.send_bytes(
+        SPENDER_ID,
+        l2e_client::l2e::io::TransferBalancesFrom::encode_call(campaign_id, 1),
+    );
+    let result = remoting.system().run_next_block();
+    let reply = |message_id| {
+        let log = result
+            .log()
+            .iter()
+            .find(|log| log.reply_to() == Some(message_id))
+            .unwrap();
+        l2e_client::l2e::io::TransferBalancesFrom::decode_reply(log.payload()).unwrap()
+    };
+    assert_eq!(reply(first), Ok(()));
+    assert_eq!(reply(second), Err(L2eError::ClaimPending));
+
+    let claimed = service_client
+        .get_claimed_stages(campaign_id, SPENDER_ID.into())
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claimed, Ok(1));
+    let balance = vft
+        .balance_of(SPENDER_ID.into())
+        .recv(vft_id)
+        .await
+        .unwrap();
+    assert_eq!(balance, 10.into());
+
+    // next stage is claimed once the first claim is over
+    let claimed = service_client
+        .transfer_balances_from(campaign_id, 1)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claimed, Ok(()));
+}
+
+#[tokio::test]
+async fn token_rewards_are_pulled_through_owner_allowance() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vft = vft_client::Vft::new(remoting.clone());
+
+    vft.mint(ACTOR_ID.into(), 100_000.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+
+    // owner has to let L2eTop pull the tokens first
+    let res = service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::InsufficientApproveTokens));
+
+    vft.approve(program_id, 30.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+    service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // spender gets no allowance of its own, the owner allowance is reported
+    let allowance = vft
+        .allowance(program_id, SPENDER_ID.into())
+        .recv(vft_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, U256::zero());
+    let allowance = service_client
+        .get_spender_token_allowances(ACTOR_ID.into(), vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Ok(30.into()));
+
+    // owner allowance is already reserved for the first spender
+    let res = service_client
+        .approve_balances(44.into(), vft_id, U256::zero(), 10.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::InsufficientApproveTokens));
+
+    // campaign in another token can't spend the approval
+    let code_id = remoting
+        .system()
+        .submit_code(extended_vft_wasm::WASM_BINARY);
+    let other_vft_id = vft_client::VftFactory::new(remoting.clone())
+        .new("Other Token".to_string(), "OTH".to_string(), 12, None)
+        .send_recv(code_id, b"other_vft")
+        .await
+        .unwrap();
+    service_client
+        .register_vft(other_vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let allowance = service_client
+        .get_spender_token_allowances(ACTOR_ID.into(), other_vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Err(L2eError::NoExistTokenApprove));
+
+    service_client
+        .mint_approve_nft(vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let campaign_id = service_client
+        .create_campaign(other_vft_id, vnft_id, 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 0, 10.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let res = service_client
+        .transfer_balances_from(campaign_id, 0)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoExistTokenApprove));
+    let claimed = service_client
+        .get_claimed_stages(campaign_id, SPENDER_ID.into())
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claimed, Ok(0));
+}
+
+#[tokio::test]
+async fn motivator_takes_back_unclaimed_approvals() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vft = vft_client::Vft::new(remoting.clone());
+
+    vft.mint(ACTOR_ID.into(), 100_000.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+    vft.approve(program_id, 30.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+
+    service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let allowance = service_client
+        .get_spender_token_allowances(ACTOR_ID.into(), vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Ok(30.into()));
+
+    // owner revokes approval, spender can't pull the tokens anymore
+    let refunded = service_client
+        .revoke_approval(SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(refunded, Ok(0));
+    let allowance = service_client
+        .get_spender_token_allowances(ACTOR_ID.into(), vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Err(L2eError::NoExistTokenApprove));
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![]));
+
+    let res = service_client
+        .revoke_approval(SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoExistApprove));
+
+    // approval can be given again and withdrawn together with the campaign
+    service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let campaign_id = service_client
+        .create_campaign(vft_id, vnft_id, 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // only the motivator can withdraw
+    let res = service_client
+        .withdraw_unclaimed(campaign_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoAuthToUpdateCampaign));
+
+    // approvals are shared, withdraw is rejected while another campaign of the motivator is live
+    let end_block = remoting.system().block_height() + 5;
+    service_client
+        .create_campaign(vft_id, vnft_id, 0, end_block)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .withdraw_unclaimed(campaign_id)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::OtherCampaignsActive));
+    let campaign = service_client
+        .get_campaign(campaign_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert!(campaign.is_ok());
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![(ACTOR_ID.into(), 0, 30.into())]));
+
+    while remoting.system().block_height() <= end_block {
+        remoting.system().run_next_block();
+    }
+
+    // last live campaign takes the approvals back
+    let refunded = service_client
+        .withdraw_unclaimed(campaign_id)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(refunded, Ok(0));
+
+    let campaign = service_client
+        .get_campaign(campaign_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(campaign, Err(L2eError::NoExistCampaign));
+    let allowance = service_client
+        .get_spender_token_allowances(ACTOR_ID.into(), vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Err(L2eError::NoExistTokenApprove));
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![]));
+}
 
 #[tokio::test]
-async fn do_something_works() {
+async fn approve_balances_rejects_vara_mismatch() {
     let remoting = GTestRemoting::new(ACTOR_ID.into());
     remoting.system().init_logger();
+    remoting.system().mint_to(ACTOR_ID, 100 * VARA_UNIT);
 
-    // Submit program code into the system
-    let program_code_id = remoting.system().submit_code(l2e::WASM_BINARY);
+    let program_id = deploy(&remoting).await;
 
-    let program_factory = l2e_client::VaraAppFactory::new(remoting.clone());
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let program_balance = remoting.system().balance_of(program_id);
 
-    let program_id = program_factory
-        .new() // Call program's constructor (see src/lib.rs:27)
-        .send_recv(program_code_id, b"salt")
+    // declared vara without attached value
+    let res = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            VARA_UNIT.into(),
+            U256::zero(),
+        )
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::VaraValueMismatch));
+
+    // attached value without declared vara is refunded
+    let res = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            U256::zero(),
+            U256::zero(),
+        )
+        .with_value(VARA_UNIT)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::VaraValueMismatch));
+    assert_eq!(remoting.system().balance_of(program_id), program_balance);
+
+    // declared and attached values differ
+    let res = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            (2 * VARA_UNIT).into(),
+            U256::zero(),
+        )
+        .with_value(VARA_UNIT)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::VaraValueMismatch));
+    assert_eq!(remoting.system().balance_of(program_id), program_balance);
+
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![]));
+
+    // escrowed vara equals declared vara
+    let res = service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            ERC20_ID.into(),
+            VARA_UNIT.into(),
+            U256::zero(),
+        )
+        .with_value(VARA_UNIT)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Ok((VARA_UNIT, U256::zero())));
+    assert_eq!(
+        remoting.system().balance_of(program_id),
+        program_balance + VARA_UNIT
+    );
+}
+
+#[tokio::test]
+async fn campaign_stage_claim_pays_vara() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+    remoting.system().mint_to(ACTOR_ID, 100 * VARA_UNIT);
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    service_client
+        .approve_balances(
+            SPENDER_ID.into(),
+            vft_id,
+            (3 * VARA_UNIT).into(),
+            U256::zero(),
+        )
+        .with_value(3 * VARA_UNIT)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .mint_approve_nft(vnft_id, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .transfer_nft_from(ACTOR_ID.into(), vnft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let campaign_id = service_client
+        .create_campaign(vft_id, vnft_id, 0, u32::MAX)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 2 * VARA_UNIT, U256::zero())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    service_client
+        .add_stage(campaign_id, 2 * VARA_UNIT, U256::zero())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let program_balance = remoting.system().balance_of(program_id);
+    let claimed = service_client
+        .transfer_balances_from(campaign_id, 0)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(claimed, Ok(()));
+    assert_eq!(
+        remoting.system().balance_of(program_id),
+        program_balance - 2 * VARA_UNIT
+    );
+
+    // remaining approved vara doesn't cover the next stage, nothing is paid
+    let res = service_client
+        .transfer_balances_from(campaign_id, 1)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::InsufficientApproveVaras));
+    assert_eq!(
+        remoting.system().balance_of(program_id),
+        program_balance - 2 * VARA_UNIT
+    );
+
+    let allowance = service_client
+        .get_spender_vara_allowances(ACTOR_ID.into())
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(allowance, Ok(VARA_UNIT));
+
+    // vara of claimed stages was paid, nothing is left for later
+    let unpaid = service_client
+        .get_unpaid_vara(SPENDER_ID.into())
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(unpaid, Ok(0));
+    let res = service_client
+        .claim_unpaid_vara()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoUnpaidVara));
+}
+
+#[tokio::test]
+async fn contracts_are_registered_disabled_and_removed() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let program_id = deploy(&remoting).await;
+    let vft_id = deploy_vft(&remoting).await;
+    let vnft_id = deploy_vnft(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    // only admin registers contracts
+    let res = service_client
+        .register_vft(vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotAdmin));
+
+    // registration probes the contract interface
+    let res = service_client
+        .register_vft(vnft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::UnexpectedContractInterface));
+    let res = service_client
+        .get_contract(vnft_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::UnknownContract));
+
+    service_client
+        .register_vft(vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .register_vft(vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::AlreadyExistTokenAddress));
+
+    // only admin disables and removes contracts
+    let res = service_client
+        .disable_contract(vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotAdmin));
+    let res = service_client
+        .remove_contract(vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotAdmin));
+
+    // disabled contract can't be used for approvals
+    service_client
+        .disable_contract(vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), U256::zero())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::ContractDisabled));
+    let erc20_address = service_client
+        .get_erc20_address()
+        .recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(erc20_address.contains(&vft_id));
+
+    service_client
+        .remove_contract(vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), U256::zero())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::UnknownContract));
+    let erc20_address = service_client
+        .get_erc20_address()
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(erc20_address, Ok(vec![ERC20_ID.into()]));
+}
+
+#[tokio::test]
+async fn constructor_contracts_are_refreshed() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, vnft_id) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    // constructor doesn't query the contracts
+    let contract = service_client
+        .get_contract(vft_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        contract,
+        Ok(ContractInfo {
+            kind: ContractKind::Vft,
+            symbol: String::new(),
+            decimals: 0,
+            enabled: true
+        })
+    );
+
+    // only admin refreshes contracts
+    let res = service_client
+        .refresh_contract(vft_id)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotAdmin));
+    let res = service_client
+        .refresh_contract(ERC20_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::UnknownContract));
+
+    let res = service_client
+        .refresh_contract(vft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        res,
+        Ok(ContractInfo {
+            kind: ContractKind::Vft,
+            symbol: "L2E".to_string(),
+            decimals: 12,
+            enabled: true
+        })
+    );
+    let contract = service_client
+        .get_contract(vft_id)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(contract, res);
+
+    let res = service_client
+        .refresh_contract(vnft_id)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        res,
+        Ok(ContractInfo {
+            kind: ContractKind::Vnft,
+            symbol: "L2EC".to_string(),
+            decimals: 0,
+            enabled: true
+        })
+    );
+}
+
+#[tokio::test]
+async fn roles_are_managed_by_admins() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let program_id = deploy(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    // deployer holds every role
+    for role in [Role::Admin, Role::Motivator, Role::Verifier, Role::Pauser] {
+        let res = service_client
+            .has_role(role, ACTOR_ID.into())
+            .recv(program_id)
+            .await
+            .unwrap();
+        assert_eq!(res, Ok(true));
+    }
+
+    // motivators can't grant roles, only admins
+    service_client
+        .grant_role(Role::Motivator, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .grant_role(Role::Motivator, 44.into())
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotAdmin));
+
+    // new motivator can create campaigns
+    let res = service_client
+        .create_campaign(ERC20_ID.into(), ERC721_ID.into(), 0, u32::MAX)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Ok(0));
+
+    service_client
+        .renounce_role(Role::Motivator)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .create_campaign(ERC20_ID.into(), ERC721_ID.into(), 0, u32::MAX)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NoAuthToCreateCampaign));
+
+    service_client
+        .revoke_role(Role::Verifier, ACTOR_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let verifiers = service_client
+        .role_members(Role::Verifier)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(verifiers, Ok(vec![]));
+    let motivators = service_client
+        .role_members(Role::Motivator)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(motivators, Ok(vec![ACTOR_ID.into()]));
+}
+
+#[tokio::test]
+async fn admin_is_handed_over_in_two_steps() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let program_id = deploy(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+
+    // admins can't be granted directly
+    let res = service_client
+        .grant_role(Role::Admin, SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::AdminMustBeProposed));
+
+    // the last admin can't leave
+    let res = service_client
+        .renounce_role(Role::Admin)
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::LastAdmin));
+
+    service_client
+        .propose_admin(SPENDER_ID.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let pending = service_client
+        .pending_admin()
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(pending, Ok(Some(SPENDER_ID.into())));
+
+    // only the proposed account can accept
+    let res = service_client
+        .accept_admin()
+        .with_args(GTestArgs::new(44.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotPendingAdmin));
+    service_client
+        .accept_admin()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // new admin takes over and the old one is removed
+    service_client
+        .revoke_role(Role::Admin, ACTOR_ID.into())
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let admins = service_client
+        .role_members(Role::Admin)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(admins, Ok(vec![SPENDER_ID.into()]));
+    let pending = service_client
+        .pending_admin()
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(pending, Ok(None));
+
+    let res = service_client
+        .revoke_role(Role::Admin, SPENDER_ID.into())
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::LastAdmin));
+}
+
+#[tokio::test]
+async fn paused_operations_are_rejected() {
+    let remoting = GTestRemoting::new(ACTOR_ID.into());
+    remoting.system().init_logger();
+
+    let (program_id, vft_id, _) = deploy_all(&remoting).await;
+
+    let mut service_client = l2e_client::L2e::new(remoting.clone());
+    let mut vft = vft_client::Vft::new(remoting.clone());
+
+    vft.mint(ACTOR_ID.into(), 100_000.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+    vft.approve(program_id, 30.into())
+        .send_recv(vft_id)
+        .await
+        .unwrap();
+    service_client
+        .approve_balances(SPENDER_ID.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // only pausers can pause
+    let res = service_client
+        .pause(Operation::ApproveBalances)
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::NotPauser));
+
+    service_client
+        .pause(Operation::ApproveBalances)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .is_paused(Operation::ApproveBalances)
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Ok(true));
+    let res = service_client
+        .is_paused(Operation::TransferBalancesFrom)
+        .recv(program_id)
         .await
         .unwrap();
+    assert_eq!(res, Ok(false));
 
-    let mut service_client = l2e_client::VaraApp::new(remoting.clone());
+    let res = service_client
+        .approve_balances(44.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Err(L2eError::Paused));
 
-    let result = service_client
-        .do_something() // Call service's method (see src/lib.rs:17)
+    // queries and refunds keep working while paused
+    let rewards = service_client
+        .get_all_owner_rewards_for_spender()
+        .with_args(GTestArgs::new(SPENDER_ID.into()))
+        .recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(rewards, Ok(vec![(ACTOR_ID.into(), 0, 30.into())]));
+    let refunded = service_client
+        .revoke_approval(SPENDER_ID.into())
         .send_recv(program_id)
         .await
         .unwrap();
+    assert_eq!(refunded, Ok(0));
 
-    assert_eq!(result, "Hello from VaraApp!".to_string());
+    service_client
+        .unpause(Operation::ApproveBalances)
+        .send_recv(program_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let res = service_client
+        .approve_balances(44.into(), vft_id, U256::zero(), 30.into())
+        .send_recv(program_id)
+        .await
+        .unwrap();
+    assert_eq!(res, Ok((0, 30.into())));
 }